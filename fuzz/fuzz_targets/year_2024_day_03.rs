@@ -0,0 +1,14 @@
+#![no_main]
+
+use advent_of_code::solution::Part;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let entry = advent_of_code::entry(2024, 3).expect("the day should be registered");
+    // Malformed input should be rejected with an error, never a panic. This day only parses its
+    // input while solving each part, so solve them too.
+    if let Ok(parsed) = entry.solver.parse(input) {
+        let _ = entry.solver.solve(Part::One, &parsed);
+        let _ = entry.solver.solve(Part::Two, &parsed);
+    }
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let entry = advent_of_code::entry(2024, 5).expect("the day should be registered");
+    // Malformed input should be rejected with an error, never a panic.
+    let _ = entry.solver.parse(input);
+});
@@ -0,0 +1,248 @@
+//! Counting the memory allocated while solving each part, with the `count-allocations` feature.
+//!
+//! The feature makes `Counter` the binary's global allocator, which wraps the system allocator and
+//! counts per thread, so that parts solved in parallel are counted separately. Without it,
+//! [`measure`] counts nothing. Counting slows allocating a little, which timings include.
+
+use std::fmt::Display;
+
+/// The memory allocated by a single thread over some span of time.
+#[derive(Clone, Copy, Hash, Debug, Default, PartialEq, Eq)]
+pub struct Allocations {
+    /// The number of allocations, including reallocations.
+    pub count: u64,
+    /// The total bytes allocated, including by reallocations that grew.
+    pub bytes: u64,
+    /// The most bytes that were live at once, beyond those that were live beforehand.
+    pub peak: u64,
+}
+
+impl Display for Allocations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocations, {}, peak {}",
+            self.count,
+            Bytes(self.bytes),
+            Bytes(self.peak)
+        )
+    }
+}
+
+/// A number of bytes, displayed in binary units, e.g., `1.50 KiB`.
+struct Bytes(u64);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [&str; 5] = ["KiB", "MiB", "GiB", "TiB", "PiB"];
+
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "only two decimal places are displayed"
+        )]
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+
+        write!(f, "{value:.2} {}", UNITS[unit])
+    }
+}
+
+/// Run `f`, counting what it allocates on this thread if the `count-allocations` feature is
+/// enabled.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    #[cfg(feature = "count-allocations")]
+    {
+        let before = counter::start();
+        let output = f();
+
+        (output, Some(counter::since(before)))
+    }
+
+    #[cfg(not(feature = "count-allocations"))]
+    (f(), None)
+}
+
+#[cfg(feature = "count-allocations")]
+pub use counter::Counter;
+
+#[cfg(feature = "count-allocations")]
+mod counter {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+    };
+
+    use super::Allocations;
+
+    /// Every allocation made by a thread since it started.
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Counts {
+        count: u64,
+        bytes: u64,
+        /// Bytes allocated minus bytes freed, which cannot go below zero, even if this thread
+        /// frees memory allocated by another.
+        live: u64,
+        /// The highest `live` has been since [`start`].
+        peak: u64,
+    }
+
+    thread_local! {
+        // `const` initialization and a `Copy` value mean this never allocates or needs dropping,
+        // which makes it safe to use from the allocator itself.
+        static COUNTS: Cell<Counts> = const {
+            Cell::new(Counts {
+                count: 0,
+                bytes: 0,
+                live: 0,
+                peak: 0,
+            })
+        };
+    }
+
+    /// Count an allocation of `allocated` bytes (if any), then a deallocation of `freed` bytes.
+    fn record(allocated: Option<usize>, freed: usize) {
+        // Fails while the thread is being torn down, which is fine to miss.
+        let _ = COUNTS.try_with(|counts| {
+            let mut new = counts.get();
+
+            if let Some(allocated) = allocated {
+                new.count += 1;
+                new.bytes += allocated.saturating_sub(freed) as u64;
+                new.live += allocated as u64;
+                new.peak = new.peak.max(new.live);
+            }
+            new.live = new.live.saturating_sub(freed as u64);
+
+            counts.set(new);
+        });
+    }
+
+    /// Start measuring this thread, returning the counts to pass to [`since`].
+    pub fn start() -> Counts {
+        COUNTS.with(|counts| {
+            let mut start = counts.get();
+            start.peak = start.live;
+            counts.set(start);
+
+            start
+        })
+    }
+
+    /// What this thread has allocated since [`start`] returned `before`.
+    pub fn since(before: Counts) -> Allocations {
+        let now = COUNTS.with(Cell::get);
+
+        Allocations {
+            count: now.count - before.count,
+            bytes: now.bytes - before.bytes,
+            peak: now.peak.saturating_sub(before.live),
+        }
+    }
+
+    /// The system allocator, counting each thread's allocations.
+    pub struct Counter;
+
+    // SAFETY: Every method forwards to `System`, which upholds the contract, and only counts
+    // successful calls on the side.
+    unsafe impl GlobalAlloc for Counter {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            // SAFETY: The caller upholds the contract of `GlobalAlloc::alloc`.
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() {
+                record(Some(layout.size()), 0);
+            }
+
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            // SAFETY: The caller upholds the contract of `GlobalAlloc::alloc_zeroed`.
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+            if !ptr.is_null() {
+                record(Some(layout.size()), 0);
+            }
+
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            // SAFETY: The caller upholds the contract of `GlobalAlloc::dealloc`.
+            unsafe { System.dealloc(ptr, layout) };
+            record(None, layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            // SAFETY: The caller upholds the contract of `GlobalAlloc::realloc`.
+            let new = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new.is_null() {
+                record(Some(new_size), layout.size());
+            }
+
+            new
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{measure, Allocations};
+
+    // The binary registers the allocator, which the library's tests do not link against.
+    #[cfg(feature = "count-allocations")]
+    #[global_allocator]
+    static ALLOCATOR: super::Counter = super::Counter;
+
+    #[test]
+    fn display() {
+        let allocations = Allocations {
+            count: 3,
+            bytes: 1536,
+            peak: 1000,
+        };
+        assert_eq!(
+            "3 allocations, 1.50 KiB, peak 1000 B",
+            allocations.to_string()
+        );
+
+        let allocations = Allocations {
+            count: 1,
+            bytes: 476 << 40,
+            peak: 5 << 20,
+        };
+        assert_eq!(
+            "1 allocations, 476.00 TiB, peak 5.00 MiB",
+            allocations.to_string()
+        );
+    }
+
+    #[test]
+    fn measure_allocations() {
+        let (vec, allocations) = measure(|| {
+            let mut vec: Vec<u64> = Vec::with_capacity(1024);
+            drop(Vec::<u8>::with_capacity(1 << 20));
+            vec.push(1);
+            vec
+        });
+        assert_eq!(vec![1], vec);
+
+        #[cfg(feature = "count-allocations")]
+        assert_eq!(
+            Some(Allocations {
+                count: 2,
+                bytes: 8192 + (1 << 20),
+                peak: 8192 + (1 << 20),
+            }),
+            allocations
+        );
+        #[cfg(not(feature = "count-allocations"))]
+        assert_eq!(None, allocations);
+    }
+}
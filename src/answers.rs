@@ -0,0 +1,301 @@
+//! A registry of confirmed answers, used to verify that solutions still produce them.
+//!
+//! Answers are stored one per line, as `YEAR DAY PART INPUT_HASH ANSWER`, e.g.,
+//! `2024 9 2 0123456789abcdef 6307279963620`. The answer is the rest of the line, so it may
+//! contain spaces, but not line breaks. Because every input has different answers, each is
+//! keyed to a hash of the input it was confirmed for (see [`hash_input`]). Blank lines and lines
+//! starting with `#` are ignored.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::solution::{Answer, Part};
+
+/// The default location of the answers file, at the root of the repository.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.txt")
+}
+
+/// A stable hash of a puzzle input, ignoring trailing whitespace.
+///
+/// Uses 64-bit FNV-1a, which (unlike [`std::hash::DefaultHasher`]) is stable between releases.
+pub fn hash_input(input: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    input.trim_end().bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+/// Identifies the answer to one part of one day, for one input.
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    /// The [`hash_input`] of the input.
+    pub input: u64,
+}
+
+/// The result of checking an answer against [`Answers`].
+#[derive(Clone, Hash, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer matches the confirmed answer.
+    Pass,
+    /// The answer does not match the confirmed answer.
+    Fail { expected: Answer },
+    /// There is no confirmed answer for this input.
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail { expected } => write!(f, "FAIL, expected {expected}"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[derive(Clone, Hash, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<Key, Answer>,
+}
+
+impl Answers {
+    /// Load the answers file at `path`, or no answers if it does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if `path` could not be read, or [`Error::Parse`] if it is malformed.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents, path),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(Error::Io {
+                path: path.into(),
+                source,
+            }),
+        }
+    }
+
+    /// Parse the contents of an answers file, reporting errors as coming from `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Parse`] if `contents` is malformed.
+    pub fn parse(contents: &str, path: &Path) -> Result<Self, Error> {
+        let mut answers = BTreeMap::new();
+
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let error = |reason| Error::Parse {
+                path: path.into(),
+                line: index + 1,
+                reason,
+            };
+
+            // The answer is the rest of the line, as it may contain spaces.
+            let mut rest = line;
+            let [year, day, part, input] = [(); 4].map(|()| {
+                let (field, tail) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                rest = tail.trim_start();
+                field
+            });
+            if rest.is_empty() {
+                return Err(error("expected `YEAR DAY PART INPUT_HASH ANSWER`"));
+            }
+
+            let key = Key {
+                year: year.parse().map_err(|_| error("invalid year"))?,
+                day: day.parse().map_err(|_| error("invalid day"))?,
+                part: part.parse().map_err(|_| error("invalid part"))?,
+                input: u64::from_str_radix(input, 16).map_err(|_| error("invalid input hash"))?,
+            };
+            let Ok(answer) = rest.parse();
+
+            if answers.insert(key, answer).is_some() {
+                return Err(error("duplicate answer"));
+            }
+        }
+
+        Ok(Self { answers })
+    }
+
+    /// Write every answer to `path`, replacing its contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if `path` could not be written to.
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        std::fs::write(path, self.to_string()).map_err(|source| Error::Io {
+            path: path.into(),
+            source,
+        })
+    }
+
+    /// Check `answer` against the confirmed answer for `key`.
+    pub fn verify(&self, key: &Key, answer: &Answer) -> Verdict {
+        match self.answers.get(key) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+
+    /// Record `answer` as the confirmed answer for `key`, returning the previous answer, if any.
+    pub fn insert(&mut self, key: Key, answer: Answer) -> Option<Answer> {
+        self.answers.insert(key, answer)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# YEAR DAY PART INPUT_HASH ANSWER")?;
+
+        for (key, answer) in &self.answers {
+            writeln!(
+                f,
+                "{} {} {} {:016x} {answer}",
+                key.year,
+                key.day,
+                key.part.number(),
+                key.input
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// When the answers file could not be read or written.
+    Io { path: PathBuf, source: io::Error },
+    /// When a line of the answers file is malformed.
+    Parse {
+        path: PathBuf,
+        line: usize,
+        reason: &'static str,
+    },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { path, source } => {
+                write!(f, "failed to access answers `{}`: {source}", path.display())
+            }
+            Self::Parse { path, line, reason } => {
+                write!(f, "{}:{line}: {reason}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            Self::Parse { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::{hash_input, Answers, Error, Key, Verdict};
+    use crate::solution::{Answer, Part};
+
+    #[test]
+    fn hash() {
+        // The FNV-1a test vectors.
+        assert_eq!(0xcbf2_9ce4_8422_2325, hash_input(""));
+        assert_eq!(0xaf63_dc4c_8601_ec8c, hash_input("a"));
+        assert_eq!(hash_input("125 17"), hash_input("125 17\n"));
+        assert_ne!(hash_input("125 17"), hash_input("125 18"));
+    }
+
+    #[test]
+    fn round_trip() {
+        let key = |part| Key {
+            year: 2024,
+            day: 11,
+            part,
+            input: hash_input("125 17"),
+        };
+        let answer = Answer::from(55312);
+
+        let mut answers = Answers::default();
+        assert_eq!(None, answers.insert(key(Part::One), answer.clone()));
+        assert_eq!(Verdict::Pass, answers.verify(&key(Part::One), &answer));
+        assert_eq!(
+            Verdict::Fail {
+                expected: answer.clone()
+            },
+            answers.verify(&key(Part::One), &0.into())
+        );
+        assert_eq!(Verdict::Unknown, answers.verify(&key(Part::Two), &0.into()));
+        assert_eq!(
+            Verdict::Unknown,
+            answers.verify(
+                &Key {
+                    input: hash_input("125 18"),
+                    ..key(Part::One)
+                },
+                &answer
+            )
+        );
+
+        let path = Path::new("answers.txt");
+        assert_eq!(answers, Answers::parse(&answers.to_string(), path).unwrap());
+
+        answers.insert(key(Part::Two), "1,2 and 3".into());
+        answers.insert(
+            Key {
+                day: 12,
+                ..key(Part::One)
+            },
+            (-3).into(),
+        );
+        assert_eq!(answers, Answers::parse(&answers.to_string(), path).unwrap());
+    }
+
+    #[test]
+    fn malformed() {
+        let path = Path::new("answers.txt");
+
+        assert!(matches!(
+            Answers::parse("# comment\n\n2024 11 1 0", path),
+            Err(Error::Parse { line: 3, .. })
+        ));
+        assert!(matches!(
+            Answers::parse("2024 11 3 0 55312", path),
+            Err(Error::Parse {
+                reason: "invalid part",
+                ..
+            })
+        ));
+        assert!(matches!(
+            Answers::parse("2024 11 1 0 55312\n2024 11 1 0 55312", path),
+            Err(Error::Parse {
+                reason: "duplicate answer",
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,221 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::Result,
+    solution::{Answer, Part, Solver},
+};
+
+/// Run `f`, returning its output alongside how long it took to run.
+pub fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let output = f();
+
+    (output, start.elapsed())
+}
+
+/// Every timing from repeatedly running a single day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Timings {
+    /// How long each run took to parse the input.
+    pub parse: Box<[Duration]>,
+    pub parts: Box<[PartTimings]>,
+}
+
+/// The answer to a single part (or the error solving it), and how long each run took to solve it.
+/// A part that errors is not run again, so has no timings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartTimings {
+    pub part: Part,
+    pub answer: Result<Answer>,
+    pub durations: Box<[Duration]>,
+}
+
+impl Timings {
+    /// Parse `input` and solve `parts`, `runs` times over. Parts that error are only run until
+    /// they do, recording the error as their answer, so that the other parts are still timed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` could not be parsed.
+    pub fn new(solver: &dyn Solver, input: &str, parts: &[Part], runs: usize) -> Result<Self> {
+        let mut parse = Vec::with_capacity(runs);
+        let mut part_timings = parts
+            .iter()
+            .map(|&part| (part, Ok(Answer::Integer(0)), Vec::with_capacity(runs)))
+            .collect::<Vec<(Part, Result<Answer>, Vec<Duration>)>>();
+
+        for _ in 0..runs {
+            let (parsed, duration) = time(|| solver.parse(input));
+            let parsed = parsed?;
+            parse.push(duration);
+
+            for (part, answer, durations) in &mut part_timings {
+                if answer.is_err() {
+                    continue;
+                }
+
+                let (result, duration) = time(|| solver.solve(*part, &parsed));
+                if result.is_ok() {
+                    durations.push(duration);
+                }
+                *answer = result;
+            }
+        }
+
+        Ok(Self {
+            parse: parse.into_boxed_slice(),
+            parts: part_timings
+                .into_iter()
+                .map(|(part, answer, durations)| PartTimings {
+                    part,
+                    answer,
+                    durations: durations.into_boxed_slice(),
+                })
+                .collect(),
+        })
+    }
+
+    /// The total time taken by each run, from parsing through solving every part that did not
+    /// error.
+    pub fn totals(&self) -> Box<[Duration]> {
+        self.parse
+            .iter()
+            .enumerate()
+            .map(|(run, parse)| {
+                *parse
+                    + self
+                        .parts
+                        .iter()
+                        .filter_map(|part| part.durations.get(run))
+                        .sum::<Duration>()
+            })
+            .collect()
+    }
+}
+
+/// Summary statistics of a set of timings.
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// The population standard deviation.
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Returns [`None`] if `samples` is empty.
+    pub fn new(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let min = *sorted.first()?;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[sorted.len() / 2 - 1] + sorted[sorted.len() / 2]) / 2
+        } else {
+            sorted[sorted.len() / 2]
+        };
+
+        #[expect(
+            clippy::cast_precision_loss,
+            reason = "there will never be anywhere near 2^52 samples"
+        )]
+        let len = sorted.len() as f64;
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / len;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / len;
+
+        Some(Self {
+            min,
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:.2?}, median {:.2?}, mean {:.2?}, stddev {:.2?}",
+            self.min, self.median, self.mean, self.stddev
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{Stats, Timings};
+    use crate::{
+        error::{Error, Result},
+        solution::{Answer, Part, Solution},
+    };
+
+    /// A stand-in for a day whose second part is not yet implemented.
+    struct Unfinished;
+
+    impl Solution for Unfinished {
+        type Input = usize;
+
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 1;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            input
+                .parse()
+                .map_err(|_| Error::parse(0, 0, "expected a number"))
+        }
+
+        fn part_one(input: &Self::Input) -> Result<Answer> {
+            Ok((*input).into())
+        }
+
+        fn part_two(_input: &Self::Input) -> Result<Answer> {
+            Err(Error::Unimplemented)
+        }
+    }
+
+    #[test]
+    fn unimplemented_parts() {
+        let timings = Timings::new(&Unfinished, "3", &[Part::One, Part::Two], 4).unwrap();
+
+        assert_eq!(4, timings.parse.len());
+        let [one, two] = &timings.parts[..] else {
+            panic!("expected a timing for each part");
+        };
+        assert_eq!(
+            (Part::One, Ok(3.into()), 4),
+            (one.part, one.answer.clone(), one.durations.len())
+        );
+        assert_eq!(
+            (Part::Two, Err(Error::Unimplemented), 0),
+            (two.part, two.answer.clone(), two.durations.len())
+        );
+        assert_eq!(4, timings.totals().len());
+
+        assert!(Timings::new(&Unfinished, "x", &[Part::One], 4).is_err());
+    }
+
+    #[test]
+    fn stats() {
+        assert_eq!(None, Stats::new(&[]));
+
+        let samples = [9, 2, 4, 4, 5, 5, 4, 7].map(Duration::from_millis);
+        let stats = Stats::new(&samples).unwrap();
+
+        assert_eq!(Duration::from_millis(2), stats.min);
+        assert_eq!(Duration::from_millis(9) / 2, stats.median);
+        // Allow for floating point error.
+        assert!((stats.mean.as_secs_f64() - 0.005).abs() < 1e-9);
+        assert!((stats.stddev.as_secs_f64() - 0.002).abs() < 1e-9);
+    }
+}
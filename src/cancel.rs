@@ -0,0 +1,87 @@
+//! Cancelling parts that run out of time.
+//!
+//! The runner gives each part a [`Token`] when it has a time budget, and cancels it once the budget
+//! is spent. A part cannot be stopped from the outside, so long-running loops should call [`check`]
+//! as they go, which returns [`Error::TimedOut`] once the part solving on this thread is cancelled.
+
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use crate::error::{Error, Result};
+
+/// A flag shared between the runner and the thread solving a part, set once the part should stop.
+#[derive(Clone, Debug, Default)]
+pub struct Token(Arc<AtomicBool>);
+
+impl Token {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+thread_local! {
+    /// The token of the part solving on this thread, if it has one.
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+/// Run `f` with `token` as the token of this thread, as checked by [`check`].
+pub fn with<T>(token: &Token, f: impl FnOnce() -> T) -> T {
+    let previous = CURRENT.replace(Some(token.clone()));
+    let output = f();
+    CURRENT.set(previous);
+
+    output
+}
+
+/// Check whether the part solving on this thread has been cancelled. Cheap enough to call on every
+/// iteration of a hot loop.
+///
+/// # Errors
+///
+/// Returns [`Error::TimedOut`] if the part has been cancelled.
+pub fn check() -> Result<()> {
+    if CURRENT.with_borrow(|token| token.as_ref().is_some_and(Token::is_cancelled)) {
+        Err(Error::TimedOut)
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{check, with, Token};
+    use crate::error::Error;
+
+    #[test]
+    fn check_tokens() {
+        assert_eq!(Ok(()), check());
+
+        let token = Token::default();
+        with(&token, || {
+            assert_eq!(Ok(()), check());
+            token.cancel();
+            assert_eq!(Err(Error::TimedOut), check());
+
+            // Only the innermost token is checked.
+            with(&Token::default(), || assert_eq!(Ok(()), check()));
+            assert_eq!(Err(Error::TimedOut), check());
+        });
+        assert_eq!(Ok(()), check());
+
+        // Other threads are unaffected.
+        with(&token, || {
+            std::thread::spawn(|| assert_eq!(Ok(()), check()))
+                .join()
+                .unwrap();
+        });
+    }
+}
@@ -0,0 +1,406 @@
+use std::{
+    fmt::Display,
+    num::{NonZeroUsize, ParseIntError},
+    ops::RangeInclusive,
+    path::PathBuf,
+    str::FromStr,
+    time::Duration,
+};
+
+use clap::{Args, Parser, Subcommand};
+
+use crate::{client, report::Format, scaffold::Template, solution::Part};
+
+#[derive(Parser, Debug)]
+#[command(version, about)]
+pub struct Cli {
+    /// Defaults to running every registered day.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Run the solutions to one or more days.
+    Run(RunArgs),
+    /// List every registered day, by year.
+    List,
+    /// Create and register the module for a new day.
+    New(NewArgs),
+    /// Download the puzzle input for a day into the cache, unless it is already cached.
+    Fetch(FetchArgs),
+    /// Solve one part of a day, then submit its answer, unless its verdict is already known.
+    Submit(SubmitArgs),
+    /// Write the examples and their answers from a saved puzzle page into a day's module.
+    Examples(ExamplesArgs),
+    /// Re-run a day's examples and parts whenever its module or input changes.
+    Watch(WatchArgs),
+}
+
+/// A single puzzle.
+#[derive(Args, Debug)]
+pub struct PuzzleArgs {
+    #[arg(long, value_parser = clap::value_parser!(u16).range(2015..))]
+    pub year: u16,
+
+    #[arg(long, value_parser = clap::value_parser!(u8).range(i64::from(Days::FIRST)..=i64::from(Days::LAST)))]
+    pub day: u8,
+}
+
+/// How to reach the website.
+#[derive(Args, Debug)]
+pub struct ClientArgs {
+    /// The session token to authenticate with, i.e., the `session` cookie of a logged in browser.
+    #[arg(long, env = "ADVENT_OF_CODE_SESSION", hide_env_values = true)]
+    pub session: String,
+
+    /// The website to talk to, e.g., a local stand-in for testing.
+    #[arg(long, env = "ADVENT_OF_CODE_URL", default_value = client::DEFAULT_BASE_URL)]
+    pub base_url: String,
+}
+
+#[derive(Args, Debug)]
+pub struct NewArgs {
+    #[command(flatten)]
+    pub puzzle: PuzzleArgs,
+
+    /// The starting point of the day's module.
+    #[arg(long, value_enum, default_value_t)]
+    pub template: Template,
+}
+
+#[derive(Args, Debug)]
+pub struct ExamplesArgs {
+    #[command(flatten)]
+    pub puzzle: PuzzleArgs,
+
+    /// The puzzle page, as saved from a browser (after solving part one, to include part two).
+    pub page: PathBuf,
+}
+
+#[derive(Args, Debug)]
+pub struct WatchArgs {
+    /// The year of the day to watch. Defaults to the latest registered year with that day.
+    #[arg(long)]
+    pub year: Option<u16>,
+
+    #[arg(long, value_parser = clap::value_parser!(u8).range(i64::from(Days::FIRST)..=i64::from(Days::LAST)))]
+    pub day: u8,
+
+    /// Read the puzzle input from this file, instead of searching for it.
+    #[arg(long)]
+    pub input: Option<PathBuf>,
+
+    /// Build with optimizations, for days that are too slow without them.
+    #[arg(long)]
+    pub release: bool,
+
+    /// How often to check for changes, in milliseconds.
+    #[arg(long, default_value_t = 500)]
+    pub interval: u64,
+}
+
+#[derive(Args, Debug)]
+#[command(group(clap::ArgGroup::new("verifying").args(["verify", "inputs"]).multiple(true)))]
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "these are independent command-line flags"
+)]
+pub struct RunArgs {
+    /// The year to run. Defaults to the latest registered year.
+    #[arg(long)]
+    pub year: Option<u16>,
+
+    /// The day to run, or a range of days, e.g., `9`, `1..=5`, or `10..`. Defaults to the latest
+    /// registered day.
+    #[arg(long, conflicts_with = "all")]
+    pub day: Option<Days>,
+
+    /// The part to run. Defaults to both.
+    #[arg(long)]
+    pub part: Option<Part>,
+
+    /// Run every registered day (of `--year`, if provided).
+    #[arg(long)]
+    pub all: bool,
+
+    /// Read the puzzle input from this file (or standard input, if `-`), instead of searching for
+    /// it. Requires that only one day is selected.
+    #[arg(long)]
+    pub input: Option<PathBuf>,
+
+    /// Time parsing and solving each part over repeated runs, instead of only printing answers.
+    #[arg(long)]
+    pub bench: bool,
+
+    /// The number of times to run each day with `--bench`.
+    #[arg(long, requires = "bench", default_value_t = RunArgs::DEFAULT_RUNS)]
+    pub runs: NonZeroUsize,
+
+    /// The history file that `--bench` appends each part's median time to, and compares it
+    /// against. Defaults to `history.txt` at the root of the repository.
+    #[arg(long, requires = "bench")]
+    pub history: Option<PathBuf>,
+
+    /// With `--bench`, neither read nor append to the history file.
+    #[arg(long, requires = "bench", conflicts_with_all = ["history", "threshold"])]
+    pub no_history: bool,
+
+    /// With `--bench`, flag parts that are slower than the median of their last few recorded times
+    /// by more than this percentage.
+    #[arg(long, requires = "bench", default_value_t = RunArgs::DEFAULT_THRESHOLD)]
+    pub threshold: u32,
+
+    /// Solve up to this many parts at once, across threads, still printing in calendar order.
+    /// `0`, or `-j` alone, uses one thread per CPU core. Defaults to solving one part at a time.
+    #[arg(
+        short,
+        long,
+        conflicts_with = "bench",
+        num_args = 0..=1,
+        default_value_t = 1,
+        default_missing_value = "0"
+    )]
+    pub jobs: usize,
+
+    /// How to print answers, their statuses, and timings.
+    #[arg(long, value_enum, default_value_t, conflicts_with = "bench")]
+    pub format: Format,
+
+    /// Check answers against the answers file, exiting unsuccessfully on any mismatch. Runs every
+    /// registered day, unless `--year` or `--day` is provided.
+    #[arg(long, conflicts_with = "bench")]
+    pub verify: bool,
+
+    /// Run each day against every input in `INPUTS/year_YYYY/day_DD/`, e.g., one per account,
+    /// printing a table of answers per day. Answers are checked against the answers file, as with
+    /// `--verify`, and inputs with answers that panicked, timed out, or failed are flagged.
+    #[arg(long, conflicts_with_all = ["input", "bench", "format", "jobs"])]
+    pub inputs: Option<PathBuf>,
+
+    /// With `--verify` or `--inputs`, record answers not yet in the answers file as confirmed.
+    #[arg(long, requires = "verifying")]
+    pub record: bool,
+
+    /// The answers file to use with `--verify` or `--inputs`. Defaults to `answers.txt` at the
+    /// root of the repository.
+    #[arg(long, requires = "verifying")]
+    pub answers: Option<PathBuf>,
+
+    /// Give parsing and each part this many milliseconds, reporting those that take longer as
+    /// timed out and moving on. Defaults to no limit.
+    #[arg(long, conflicts_with = "bench", value_parser = clap::value_parser!(u64).range(1..))]
+    pub timeout: Option<u64>,
+}
+
+impl RunArgs {
+    pub const DEFAULT_RUNS: NonZeroUsize = NonZeroUsize::new(10).unwrap();
+    pub const DEFAULT_THRESHOLD: u32 = 10;
+
+    /// Equivalent to `run --all`.
+    pub fn all() -> Self {
+        Self {
+            all: true,
+            ..Default::default()
+        }
+    }
+
+    /// Whether every day (of [`Self::year`], if provided) should be run, either because of
+    /// [`Self::all`], or because of [`Self::verify`] without [`Self::day`].
+    pub const fn all_days(&self) -> bool {
+        self.all || (self.verify && self.day.is_none())
+    }
+
+    /// The number of threads selected by [`Self::jobs`].
+    pub fn jobs(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.jobs)
+            .unwrap_or_else(|| std::thread::available_parallelism().unwrap_or(NonZeroUsize::MIN))
+    }
+
+    /// The time budget selected by [`Self::timeout`].
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout.map(Duration::from_millis)
+    }
+
+    /// The parts selected by [`Self::part`].
+    pub fn parts(&self) -> Box<[Part]> {
+        self.part
+            .map_or_else(|| Part::all().into(), |part| [part].into())
+    }
+}
+
+impl Default for RunArgs {
+    fn default() -> Self {
+        Self {
+            year: None,
+            day: None,
+            part: None,
+            all: false,
+            input: None,
+            bench: false,
+            runs: Self::DEFAULT_RUNS,
+            history: None,
+            no_history: false,
+            threshold: Self::DEFAULT_THRESHOLD,
+            jobs: 1,
+            format: Format::default(),
+            verify: false,
+            inputs: None,
+            record: false,
+            answers: None,
+            timeout: None,
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct FetchArgs {
+    #[command(flatten)]
+    pub puzzle: PuzzleArgs,
+
+    #[command(flatten)]
+    pub client: ClientArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct SubmitArgs {
+    #[command(flatten)]
+    pub puzzle: PuzzleArgs,
+
+    #[arg(long)]
+    pub part: Part,
+
+    /// Read the puzzle input from this file (or standard input, if `-`), instead of searching for
+    /// it.
+    #[arg(long)]
+    pub input: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub client: ClientArgs,
+}
+
+/// An inclusive range of days, parsed from a single day (`9`) or a Rust-style range (`1..=5`,
+/// `1..6`, `10..`, or `..=5`).
+#[derive(Clone, Hash, Debug, PartialEq, Eq)]
+pub struct Days {
+    range: RangeInclusive<u8>,
+}
+
+impl Days {
+    pub const FIRST: u8 = 1;
+    pub const LAST: u8 = 25;
+
+    pub fn contains(&self, day: u8) -> bool {
+        self.range.contains(&day)
+    }
+}
+
+impl FromStr for Days {
+    type Err = ParseDaysError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn parse_or(s: &str, default: u8) -> Result<u8, ParseDaysError> {
+            if s.is_empty() {
+                return Ok(default);
+            }
+
+            let day = s.trim().parse().map_err(ParseDaysError::InvalidDay)?;
+
+            if (Days::FIRST..=Days::LAST).contains(&day) {
+                Ok(day)
+            } else {
+                Err(ParseDaysError::OutOfRange(day))
+            }
+        }
+
+        let range = if let Some((start, end)) = s.split_once("..=") {
+            parse_or(start, Self::FIRST)?..=parse_or(end, Self::LAST)?
+        } else if let Some((start, end)) = s.split_once("..") {
+            // `..26` is a valid exclusive range, even though `26` is not a valid day.
+            let end = if end.is_empty() {
+                Self::LAST
+            } else {
+                end.trim()
+                    .parse::<u8>()
+                    .map_err(ParseDaysError::InvalidDay)?
+                    .checked_sub(1)
+                    .ok_or(ParseDaysError::Empty)?
+            };
+
+            if end > Self::LAST {
+                return Err(ParseDaysError::OutOfRange(end));
+            }
+
+            parse_or(start, Self::FIRST)?..=end
+        } else {
+            if s.is_empty() {
+                return Err(ParseDaysError::Empty);
+            }
+
+            let day = parse_or(s, Self::FIRST)?;
+
+            day..=day
+        };
+
+        if range.is_empty() {
+            return Err(ParseDaysError::Empty);
+        }
+
+        Ok(Self { range })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseDaysError {
+    /// When a day is not a number.
+    InvalidDay(ParseIntError),
+    /// When a day is not between [`Days::FIRST`] and [`Days::LAST`].
+    OutOfRange(u8),
+    /// When a range does not contain any days.
+    Empty,
+}
+
+impl Display for ParseDaysError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidDay(err) => write!(f, "invalid day: {err}"),
+            Self::OutOfRange(day) => write!(
+                f,
+                "day {day} is not between {} and {}",
+                Days::FIRST,
+                Days::LAST
+            ),
+            Self::Empty => write!(f, "range does not contain any days"),
+        }
+    }
+}
+
+impl std::error::Error for ParseDaysError {}
+
+#[cfg(test)]
+mod test {
+    use super::{Days, ParseDaysError};
+
+    fn days(s: &str) -> Result<Vec<u8>, ParseDaysError> {
+        let days = s.parse::<Days>()?;
+
+        Ok((0..=u8::MAX).filter(|&day| days.contains(day)).collect())
+    }
+
+    #[test]
+    fn parse_days() {
+        assert_eq!(Ok(vec![9]), days("9"));
+        assert_eq!(Ok(vec![1, 2, 3, 4, 5]), days("1..=5"));
+        assert_eq!(Ok(vec![1, 2, 3, 4, 5]), days("1..6"));
+        assert_eq!(Ok(vec![1, 2, 3]), days("..=3"));
+        assert_eq!(Ok(vec![23, 24, 25]), days("23.."));
+        assert_eq!(Ok((1..=25).collect()), days(".."));
+
+        assert_eq!(Err(ParseDaysError::OutOfRange(0)), days("0"));
+        assert_eq!(Err(ParseDaysError::OutOfRange(26)), days("1..=26"));
+        assert_eq!(Err(ParseDaysError::Empty), days("5..=4"));
+        assert_eq!(Err(ParseDaysError::Empty), days("5..5"));
+        assert_eq!(Err(ParseDaysError::Empty), days(""));
+        assert!(matches!(days("five"), Err(ParseDaysError::InvalidDay(_))));
+    }
+}
@@ -0,0 +1,238 @@
+//! A client for the Advent of Code website, authenticated by a session token.
+
+use std::{fmt::Display, time::Duration};
+
+use ureq::{http::Response, Agent, Body};
+
+use crate::solution::{Answer, Part};
+
+/// The website that [`Client`]s talk to, unless another is configured (e.g., a local stand-in).
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies this tool to the website, as its operator requests of automated tools.
+const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (",
+    env!("CARGO_PKG_REPOSITORY"),
+    ")",
+);
+
+pub struct Client {
+    agent: Agent,
+    /// E.g., `https://adventofcode.com`, without a trailing slash.
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    /// A client for the website at `base_url` (e.g., [`DEFAULT_BASE_URL`]), authenticated as the
+    /// owner of the `session` cookie.
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+        }
+    }
+
+    /// Download the puzzle input for a day.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Request`] if the request failed, or [`Error::Status`] if the website
+    /// refused it.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, Error> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let response = self.agent.get(&url).header("Cookie", &self.cookie()).call();
+
+        Self::read(url, response)
+    }
+
+    /// Submit an answer to one part of a day, returning the page describing the verdict.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Request`] if the request failed, or [`Error::Status`] if the website
+    /// refused it.
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &Answer) -> Result<String, Error> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &self.cookie())
+            .send_form([
+                ("level", part.number().to_string()),
+                ("answer", answer.to_string()),
+            ]);
+
+        Self::read(url, response)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// The body of a successful `response` to a request to `url`.
+    fn read(url: String, response: Result<Response<Body>, ureq::Error>) -> Result<String, Error> {
+        let mut response = response.map_err(|source| Error::Request {
+            url: url.clone(),
+            source,
+        })?;
+
+        let status = response.status().as_u16();
+        if status != 200 {
+            return Err(Error::Status { url, status });
+        }
+
+        response
+            .body_mut()
+            .read_to_string()
+            .map_err(|source| Error::Request { url, source })
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// When a request could not be made, or its response could not be read.
+    Request { url: String, source: ureq::Error },
+    /// When the website responded with an unsuccessful status code.
+    Status { url: String, status: u16 },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Request { url, source } => write!(f, "failed to request `{url}`: {source}"),
+            Self::Status { url, status } => {
+                write!(f, "`{url}` responded with status {status}")?;
+
+                match status {
+                    404 => write!(f, " (is the puzzle unlocked yet?)"),
+                    400 | 500 => write!(f, " (is the session token valid?)"),
+                    _ => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Request { source, .. } => Some(source),
+            Self::Status { .. } => None,
+        }
+    }
+}
+
+/// A local stand-in for the website, for testing [`Client`]s against.
+#[cfg(test)]
+pub(crate) mod stand_in {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::JoinHandle,
+    };
+
+    /// Serve each of `responses` (as a status code and body) to one connection, in order.
+    ///
+    /// Returns the base URL of the server, and a handle that returns every request it received,
+    /// as the request line, headers, and body.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = std::thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|(status, body)| {
+                    let (stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream);
+                    let mut request = String::new();
+                    let mut content_length = 0;
+
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+
+                        if let Some((name, value)) = line.split_once(':') {
+                            if name.eq_ignore_ascii_case("content-length") {
+                                content_length = value.trim().parse().unwrap();
+                            }
+                        }
+
+                        request.push_str(&line);
+                        if line == "\r\n" || line.is_empty() {
+                            break;
+                        }
+                    }
+
+                    let mut content = vec![0; content_length];
+                    reader.read_exact(&mut content).unwrap();
+                    request.push_str(&String::from_utf8(content).unwrap());
+
+                    write!(
+                        reader.get_mut(),
+                        "HTTP/1.1 {status} Stand-In\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len(),
+                    )
+                    .unwrap();
+
+                    request
+                })
+                .collect()
+        });
+
+        (base_url, handle)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{stand_in, Client, Error};
+    use crate::solution::Part;
+
+    #[test]
+    fn input() {
+        let (base_url, server) = stand_in::serve(vec![(200, "125 17\n"), (404, "Not Found")]);
+        let client = Client::new(&format!("{base_url}/"), "token");
+
+        assert_eq!("125 17\n", client.input(2024, 11).unwrap());
+        assert!(matches!(
+            client.input(2024, 26),
+            Err(Error::Status { status: 404, .. })
+        ));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2024/day/11/input HTTP/1.1\r\n"));
+        assert!(requests[0]
+            .to_ascii_lowercase()
+            .contains("cookie: session=token\r\n"));
+        assert!(requests[1].starts_with("GET /2024/day/26/input HTTP/1.1\r\n"));
+    }
+
+    #[test]
+    fn submit() {
+        let (base_url, server) =
+            stand_in::serve(vec![(200, "<main>That's the right answer!</main>")]);
+        let client = Client::new(&base_url, "token");
+
+        assert_eq!(
+            "<main>That's the right answer!</main>",
+            client.submit(2024, 1, Part::Two, &"a b".into()).unwrap()
+        );
+
+        let request = &server.join().unwrap()[0];
+        assert!(request.starts_with("POST /2024/day/1/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=a+b"));
+    }
+}
@@ -0,0 +1,92 @@
+use std::{fmt::Display, num::TryFromIntError};
+
+/// A [`std::result::Result`] defaulting to this crate's [`Error`].
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Why a solution failed to parse its input or solve a part.
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// When the puzzle input is malformed. `line` and `column` start from one.
+    Parse {
+        line: usize,
+        column: usize,
+        reason: &'static str,
+    },
+    /// When the puzzle input is well-formed, but breaks an assumption the solution relies upon.
+    InvalidAssumption(&'static str),
+    /// When an intermediate value or answer does not fit in its type.
+    Overflow,
+    /// When a part has not been implemented yet.
+    Unimplemented,
+    /// When parsing or a part ran out of time, and was cancelled.
+    TimedOut,
+}
+
+impl Error {
+    /// A [`Self::Parse`] from zero-based line and column indices, as given by
+    /// [`Iterator::enumerate`].
+    pub const fn parse(line_index: usize, column_index: usize, reason: &'static str) -> Self {
+        Self::Parse {
+            line: line_index + 1,
+            column: column_index + 1,
+            reason,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse {
+                line,
+                column,
+                reason,
+            } => write!(f, "malformed input at {line}:{column}: {reason}"),
+            Self::InvalidAssumption(assumption) => {
+                write!(f, "input breaks an assumption: {assumption}")
+            }
+            Self::Overflow => write!(f, "arithmetic overflow"),
+            Self::Unimplemented => write!(f, "not yet implemented"),
+            Self::TimedOut => write!(f, "timed out"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<TryFromIntError> for Error {
+    fn from(_: TryFromIntError) -> Self {
+        Self::Overflow
+    }
+}
+
+/// Split `line` by `separator`, alongside the zero-based column that each piece starts at.
+pub fn split_columns(line: &str, separator: char) -> impl Iterator<Item = (usize, &str)> {
+    line.split(separator).scan(0, move |column, piece| {
+        let start = *column;
+        *column += piece.len() + separator.len_utf8();
+
+        Some((start, piece))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{split_columns, Error};
+
+    #[test]
+    fn columns() {
+        assert_eq!(
+            vec![(0, "3"), (2, ""), (3, ""), (4, "4")],
+            split_columns("3   4", ' ').collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Error::Parse {
+                line: 1,
+                column: 4,
+                reason: "expected a number"
+            },
+            Error::parse(0, 3, "expected a number")
+        );
+    }
+}
@@ -0,0 +1,367 @@
+//! A local history of benchmark timings, used to notice parts that have become slower.
+//!
+//! Each `--bench` run appends a line per part, as `TIMESTAMP COMMIT YEAR DAY PART INPUT_HASH
+//! NANOS`, e.g., `1734652800 1a2b3c4d5e6f-dirty 2024 9 2 0123456789abcdef 1203000`. The
+//! timestamp is in seconds since the Unix epoch, the commit is `-` if it is unknown, and the
+//! duration is the median of the run's timings. As timings depend on the input, each is keyed to a
+//! hash of it, as in [`answers`](crate::answers). Blank lines and lines starting with `#` are
+//! ignored.
+
+use std::{
+    fmt::Display,
+    fs::OpenOptions,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime},
+};
+
+use crate::{answers::Key, bench::Stats, solution::Part};
+
+/// How many of the most recent timings of a part its baseline is taken from.
+pub const BASELINE_RUNS: usize = 5;
+
+/// The default location of the history file, at the root of the repository.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("history.txt")
+}
+
+/// The commit checked out in the repository, suffixed with `-dirty` if there are uncommitted
+/// changes, or [`None`] if it could not be found, e.g., because `git` is not installed.
+pub fn commit() -> Option<String> {
+    let output = Command::new("git")
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        // Excluding every tag always describes the commit by its hash alone.
+        .args([
+            "describe",
+            "--always",
+            "--dirty",
+            "--abbrev=12",
+            "--exclude=*",
+        ])
+        .output()
+        .ok()?;
+    let commit = String::from_utf8(output.stdout).ok()?;
+
+    (output.status.success() && !commit.trim().is_empty()).then(|| commit.trim().to_owned())
+}
+
+/// The seconds since the Unix epoch.
+pub fn timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// A timing of one part of one day, for one input.
+#[derive(Clone, Hash, Debug, PartialEq, Eq)]
+pub struct Record {
+    /// The seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub commit: Option<String>,
+    pub key: Key,
+    pub duration: Duration,
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {} {:016x} {}",
+            self.timestamp,
+            self.commit.as_deref().unwrap_or("-"),
+            self.key.year,
+            self.key.day,
+            self.key.part.number(),
+            self.key.input,
+            self.duration.as_nanos()
+        )
+    }
+}
+
+/// When a part took longer than its baseline by more than the threshold.
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq)]
+pub struct Regression {
+    pub baseline: Duration,
+    pub duration: Duration,
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let slower = self.duration.as_secs_f64() / self.baseline.as_secs_f64() - 1.0;
+
+        write!(
+            f,
+            "SLOWER: {:.2?} is {:.0}% over the baseline of {:.2?}",
+            self.duration,
+            slower * 100.0,
+            self.baseline
+        )
+    }
+}
+
+/// Every recorded timing, oldest first, and those not yet saved.
+#[derive(Clone, Hash, Debug, Default, PartialEq, Eq)]
+pub struct History {
+    records: Vec<Record>,
+    /// How many of `records` have been saved.
+    saved: usize,
+}
+
+impl History {
+    /// Load the history file at `path`, or no history if it does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if `path` could not be read, or [`Error::Parse`] if it is malformed.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents, path),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(Error::Io {
+                path: path.into(),
+                source,
+            }),
+        }
+    }
+
+    /// Parse the contents of a history file, reporting errors as coming from `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Parse`] if `contents` is malformed.
+    pub fn parse(contents: &str, path: &Path) -> Result<Self, Error> {
+        let mut records = vec![];
+
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let error = |reason| Error::Parse {
+                path: path.into(),
+                line: index + 1,
+                reason,
+            };
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [timestamp, commit, year, day, part, input, nanos] = fields[..] else {
+                return Err(error(
+                    "expected `TIMESTAMP COMMIT YEAR DAY PART INPUT_HASH NANOS`",
+                ));
+            };
+
+            records.push(Record {
+                timestamp: timestamp.parse().map_err(|_| error("invalid timestamp"))?,
+                commit: (commit != "-").then(|| commit.to_owned()),
+                key: Key {
+                    year: year.parse().map_err(|_| error("invalid year"))?,
+                    day: day.parse().map_err(|_| error("invalid day"))?,
+                    part: part.parse::<Part>().map_err(|_| error("invalid part"))?,
+                    input: u64::from_str_radix(input, 16)
+                        .map_err(|_| error("invalid input hash"))?,
+                },
+                duration: nanos
+                    .parse()
+                    .map(Duration::from_nanos)
+                    .map_err(|_| error("invalid duration"))?,
+            });
+        }
+
+        Ok(Self {
+            saved: records.len(),
+            records,
+        })
+    }
+
+    /// Append every record not yet saved to `path`, creating it if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if `path` could not be written to.
+    pub fn save(&mut self, path: &Path) -> Result<(), Error> {
+        let unsaved = &self.records[self.saved..];
+        if unsaved.is_empty() {
+            return Ok(());
+        }
+
+        let io_error = |source| Error::Io {
+            path: path.into(),
+            source,
+        };
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(io_error)?;
+
+        let mut lines = String::new();
+        if file.metadata().map_err(io_error)?.len() == 0 {
+            lines.push_str("# TIMESTAMP COMMIT YEAR DAY PART INPUT_HASH NANOS\n");
+        }
+        for record in unsaved {
+            lines.push_str(&record.to_string());
+            lines.push('\n');
+        }
+        file.write_all(lines.as_bytes()).map_err(io_error)?;
+
+        self.saved = self.records.len();
+        Ok(())
+    }
+
+    /// The median of the [`BASELINE_RUNS`] most recent timings of `key`, if there are any.
+    pub fn baseline(&self, key: &Key) -> Option<Duration> {
+        let recent: Vec<Duration> = self
+            .records
+            .iter()
+            .rev()
+            .filter(|record| record.key == *key)
+            .take(BASELINE_RUNS)
+            .map(|record| record.duration)
+            .collect();
+
+        Stats::new(&recent).map(|stats| stats.median)
+    }
+
+    /// Record a timing, returning a [`Regression`] if it is slower than its [`Self::baseline`] by
+    /// more than `threshold` percent.
+    pub fn push(&mut self, record: Record, threshold: u32) -> Option<Regression> {
+        let regression = self.baseline(&record.key).and_then(|baseline| {
+            let limit = baseline.as_secs_f64() * (1.0 + f64::from(threshold) / 100.0);
+
+            (record.duration.as_secs_f64() > limit).then_some(Regression {
+                baseline,
+                duration: record.duration,
+            })
+        });
+        self.records.push(record);
+
+        regression
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// When the history file could not be read or written.
+    Io { path: PathBuf, source: io::Error },
+    /// When a line of the history file is malformed.
+    Parse {
+        path: PathBuf,
+        line: usize,
+        reason: &'static str,
+    },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { path, source } => {
+                write!(f, "failed to access history `{}`: {source}", path.display())
+            }
+            Self::Parse { path, line, reason } => {
+                write!(f, "{}:{line}: {reason}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            Self::Parse { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, time::Duration};
+
+    use super::{History, Record, Regression};
+    use crate::{answers::Key, solution::Part};
+
+    fn record(part: Part, millis: u64) -> Record {
+        Record {
+            timestamp: 1_734_652_800,
+            commit: Some("1a2b3c4d5e6f".to_owned()),
+            key: Key {
+                year: 2024,
+                day: 9,
+                part,
+                input: 0x0123_4567_89ab_cdef,
+            },
+            duration: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn baselines() {
+        let mut history = History::default();
+        assert_eq!(None, history.push(record(Part::One, 100), 10));
+
+        // Only the most recent runs of the same part count towards its baseline.
+        for millis in [1000, 10, 10, 12, 14, 14] {
+            history.push(record(Part::One, millis), 10);
+        }
+        history.push(record(Part::Two, 1), 10);
+        assert_eq!(
+            Some(Duration::from_millis(12)),
+            history.baseline(&record(Part::One, 0).key)
+        );
+
+        assert_eq!(None, history.push(record(Part::One, 13), 10));
+        assert_eq!(
+            Some(Regression {
+                baseline: Duration::from_millis(13),
+                duration: Duration::from_millis(15),
+            }),
+            history.push(record(Part::One, 15), 10)
+        );
+        assert_eq!(None, history.push(record(Part::One, 15), 25));
+        assert_eq!(
+            "SLOWER: 15.00ms is 25% over the baseline of 12.00ms",
+            Regression {
+                baseline: Duration::from_millis(12),
+                duration: Duration::from_millis(15),
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path = std::env::temp_dir().join("advent_of_code_history.txt");
+        let _ = std::fs::remove_file(&path);
+
+        let mut history = History::load(&path).unwrap();
+        history.push(record(Part::One, 12), 10);
+        history.save(&path).unwrap();
+        history.push(
+            Record {
+                commit: None,
+                ..record(Part::Two, 3)
+            },
+            10,
+        );
+        history.save(&path).unwrap();
+        // Saving again appends nothing.
+        history.save(&path).unwrap();
+
+        assert_eq!(
+            "# TIMESTAMP COMMIT YEAR DAY PART INPUT_HASH NANOS
+1734652800 1a2b3c4d5e6f 2024 9 1 0123456789abcdef 12000000
+1734652800 - 2024 9 2 0123456789abcdef 3000000
+",
+            std::fs::read_to_string(&path).unwrap()
+        );
+        assert_eq!(history, History::load(&path).unwrap());
+
+        std::fs::remove_file(path).unwrap();
+
+        assert!(History::parse("1734652800 - 2024 9 1 0123456789abcdef", Path::new("")).is_err());
+        assert!(History::parse("1734652800 - 2024 9 3 0123456789abcdef 1", Path::new("")).is_err());
+    }
+}
@@ -0,0 +1,384 @@
+//! Locating and reading puzzle inputs at runtime.
+//!
+//! For a given day, the input is the first of:
+//!
+//! 1. An explicitly provided path, or standard input if the path is [`STDIN`].
+//! 2. `$ADVENT_OF_CODE_INPUTS/year_YYYY/day_DD.txt`, if [`ENV_VAR`] is set.
+//! 3. `src/year_YYYY/day_DD/data.txt`, alongside the day's module.
+//! 4. `inputs/year_YYYY/day_DD.txt` inside of the [`cache_directory`], where [`fetch`] downloads
+//!    inputs to.
+//! 5. The input embedded into the binary, with the `embed-inputs` feature.
+//!
+//! Alternatively, a day can be run against every one of its [`named`] inputs, e.g., one per
+//! account.
+
+use std::{
+    fmt::Display,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::client::{self, Client};
+
+/// The environment variable pointing to a directory of puzzle inputs.
+pub const ENV_VAR: &str = "ADVENT_OF_CODE_INPUTS";
+
+/// The environment variable pointing to the directory that fetched inputs are cached in.
+pub const CACHE_ENV_VAR: &str = "ADVENT_OF_CODE_CACHE";
+
+/// The path that reads the input from standard input instead, e.g., `--input -`.
+pub const STDIN: &str = "-";
+
+/// The path to a day's input alongside its module in the source tree, e.g.,
+/// `src/year_2024/day_09/data.txt`.
+pub fn module_path(year: u16, day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join(format!("year_{year}"))
+        .join(format!("day_{day:02}"))
+        .join("data.txt")
+}
+
+/// The path to a day's input inside of the directory set by [`ENV_VAR`], if it is set. E.g.,
+/// `$ADVENT_OF_CODE_INPUTS/year_2024/day_09.txt`.
+pub fn env_path(year: u16, day: u8) -> Option<PathBuf> {
+    let directory = std::env::var_os(ENV_VAR)?;
+
+    Some(
+        PathBuf::from(directory)
+            .join(format!("year_{year}"))
+            .join(format!("day_{day:02}.txt")),
+    )
+}
+
+/// The directory that fetched inputs are cached in, outside of the source tree. This is the first
+/// of:
+///
+/// 1. `$ADVENT_OF_CODE_CACHE`, if [`CACHE_ENV_VAR`] is set.
+/// 2. `$XDG_CACHE_HOME/advent_of_code`, if `XDG_CACHE_HOME` is set.
+/// 3. `$HOME/.cache/advent_of_code`, if `HOME` is set.
+pub fn cache_directory() -> Option<PathBuf> {
+    let var = |name| std::env::var_os(name).filter(|value| !value.is_empty());
+
+    var(CACHE_ENV_VAR)
+        .map(PathBuf::from)
+        .or_else(|| var("XDG_CACHE_HOME").map(|cache| Path::new(&cache).join("advent_of_code")))
+        .or_else(|| var("HOME").map(|home| Path::new(&home).join(".cache/advent_of_code")))
+}
+
+/// The path to a day's input inside of a cache directory (see [`cache_directory`]), e.g.,
+/// `~/.cache/advent_of_code/inputs/year_2024/day_09.txt`.
+pub fn cache_path(cache: &Path, year: u16, day: u8) -> PathBuf {
+    cache
+        .join("inputs")
+        .join(format!("year_{year}"))
+        .join(format!("day_{day:02}.txt"))
+}
+
+/// Every path that will be searched for a day's input, in order of precedence.
+pub fn search_paths(year: u16, day: u8) -> Vec<PathBuf> {
+    env_path(year, day)
+        .into_iter()
+        .chain([module_path(year, day)])
+        .chain(cache_directory().map(|cache| cache_path(&cache, year, day)))
+        .collect()
+}
+
+/// Where a fetched input is, and whether it had to be downloaded.
+#[derive(Clone, Hash, Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// When the input was already cached, so it was not downloaded again.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Download the puzzle input for a day into `cache` (see [`cache_path`]), unless it is already
+/// there.
+///
+/// # Errors
+///
+/// Returns [`Error::Fetch`] if the input could not be downloaded, or [`Error::Io`] if it could not
+/// be written to the cache.
+#[expect(
+    clippy::missing_panics_doc,
+    reason = "cache paths always have a parent directory"
+)]
+pub fn fetch(client: &Client, cache: &Path, year: u16, day: u8) -> Result<Fetched, Error> {
+    let path = cache_path(cache, year, day);
+    if path.is_file() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = client.input(year, day).map_err(Error::Fetch)?;
+
+    let io_error = |path: &Path| {
+        let path = path.to_owned();
+        move |source| Error::Io { path, source }
+    };
+    let directory = path
+        .parent()
+        .expect("cache paths are inside of a directory");
+    std::fs::create_dir_all(directory).map_err(io_error(directory))?;
+
+    // Write to a temporary file first, such that an interrupted write is never mistaken for a
+    // cached input.
+    let partial = path.with_extension("txt.partial");
+    std::fs::write(&partial, input).map_err(io_error(&partial))?;
+    std::fs::rename(&partial, &path).map_err(io_error(&path))?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+/// Read the puzzle input for a day.
+///
+/// If `path` is provided, only `path` is read, or standard input if `path` is [`STDIN`].
+/// Otherwise, [`search_paths`] are searched in order, falling back to `embedded`.
+///
+/// # Errors
+///
+/// Returns [`Error::Missing`] if no input could be found, or [`Error::Io`] if an input could not
+/// be read.
+pub fn read(
+    year: u16,
+    day: u8,
+    path: Option<&Path>,
+    embedded: Option<&'static str>,
+) -> Result<String, Error> {
+    if let Some(path) = path {
+        return read_path(path);
+    }
+
+    let searched = search_paths(year, day);
+
+    for path in &searched {
+        if path.is_file() {
+            return read_path(path);
+        }
+    }
+
+    embedded
+        .map(ToOwned::to_owned)
+        .ok_or_else(|| Error::Missing {
+            year,
+            day,
+            searched: searched.into_boxed_slice(),
+        })
+}
+
+/// The directory of a day's named inputs inside of a directory of inputs, e.g.,
+/// `DIRECTORY/year_2024/day_09`.
+pub fn named_directory(directory: &Path, year: u16, day: u8) -> PathBuf {
+    directory
+        .join(format!("year_{year}"))
+        .join(format!("day_{day:02}"))
+}
+
+/// Every named input in a day's [`named_directory`], sorted by name. E.g., the input named `alice`
+/// is read from `DIRECTORY/year_2024/day_09/alice.txt`.
+///
+/// # Errors
+///
+/// Returns [`Error::Io`] if the day's directory could not be read.
+pub fn named(directory: &Path, year: u16, day: u8) -> Result<Vec<(String, PathBuf)>, Error> {
+    let directory = named_directory(directory, year, day);
+    let io_error = |source| Error::Io {
+        path: directory.clone(),
+        source,
+    };
+
+    let mut inputs = vec![];
+    for entry in std::fs::read_dir(&directory).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+
+        if path.extension().is_some_and(|extension| extension == "txt") && path.is_file() {
+            if let Some(name) = path.file_stem() {
+                inputs.push((name.to_string_lossy().into_owned(), path));
+            }
+        }
+    }
+    inputs.sort_unstable();
+
+    Ok(inputs)
+}
+
+/// Whether `path` is [`STDIN`], rather than a file.
+pub fn is_stdin(path: &Path) -> bool {
+    path == Path::new(STDIN)
+}
+
+fn read_path(path: &Path) -> Result<String, Error> {
+    read_path_or(path, io::stdin())
+}
+
+/// Read `path`, or `stdin` if `path` is [`STDIN`].
+fn read_path_or(path: &Path, stdin: impl Read) -> Result<String, Error> {
+    let contents = if is_stdin(path) {
+        io::read_to_string(stdin)
+    } else {
+        std::fs::read_to_string(path)
+    };
+
+    contents.map_err(|source| Error::Io {
+        path: path.into(),
+        source,
+    })
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// When no input exists in any of the searched locations.
+    Missing {
+        year: u16,
+        day: u8,
+        searched: Box<[PathBuf]>,
+    },
+    /// When an input exists, but could not be read, or a fetched input could not be written.
+    Io { path: PathBuf, source: io::Error },
+    /// When an input could not be downloaded.
+    Fetch(client::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing {
+                year,
+                day,
+                searched,
+            } => {
+                write!(f, "no input for {year} day {day}, searched ")?;
+
+                for (index, path) in searched.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(f, "`{}`", path.display())?;
+                }
+
+                Ok(())
+            }
+            Self::Io { path, source } if is_stdin(path) => {
+                write!(f, "failed to read input from standard input: {source}")
+            }
+            Self::Io { path, source } => {
+                write!(f, "failed to access input `{}`: {source}", path.display())
+            }
+            Self::Fetch(err) => write!(f, "failed to fetch input: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Missing { .. } => None,
+            Self::Io { source, .. } => Some(source),
+            Self::Fetch(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::{fetch, is_stdin, named, read, read_path_or, Error, Fetched};
+    use crate::client::{stand_in, Client};
+
+    #[test]
+    fn explicit_path() {
+        let path = std::env::temp_dir().join("advent_of_code_explicit_path.txt");
+        std::fs::write(&path, "125 17").unwrap();
+
+        assert_eq!("125 17", read(2024, 11, Some(&path), None).unwrap());
+        assert!(matches!(
+            read(2024, 11, Some(&path.with_extension("missing")), None),
+            Err(Error::Io { .. })
+        ));
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn stdin() {
+        assert!(is_stdin(Path::new("-")));
+        // A file named `-` can still be read by its path.
+        assert!(!is_stdin(Path::new("./-")));
+        assert!(!is_stdin(Path::new("-.txt")));
+
+        assert_eq!(
+            "125 17",
+            read_path_or(Path::new("-"), b"125 17".as_slice()).unwrap()
+        );
+        let err = read_path_or(Path::new("-"), [0xff].as_slice()).unwrap_err();
+        assert!(matches!(&err, Error::Io { path, .. } if is_stdin(path)));
+        assert_eq!(
+            "failed to read input from standard input: stream did not contain valid UTF-8",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn missing() {
+        // Day 26 never exists, so this can only ever fall through to `embedded`.
+        assert_eq!("embedded", read(2024, 26, None, Some("embedded")).unwrap());
+        assert!(matches!(
+            read(2024, 26, None, None),
+            Err(Error::Missing { day: 26, .. })
+        ));
+    }
+
+    #[test]
+    fn named_inputs() {
+        let directory = std::env::temp_dir().join("advent_of_code_named_inputs");
+        let _ = std::fs::remove_dir_all(&directory);
+        let day = directory.join("year_2024/day_11");
+        std::fs::create_dir_all(day.join("nested.txt")).unwrap();
+        for file in ["bob.txt", "alice.txt", "notes.md"] {
+            std::fs::write(day.join(file), "125 17").unwrap();
+        }
+
+        assert_eq!(
+            vec![
+                ("alice".to_owned(), day.join("alice.txt")),
+                ("bob".to_owned(), day.join("bob.txt")),
+            ],
+            named(&directory, 2024, 11).unwrap()
+        );
+        assert!(matches!(named(&directory, 2024, 12), Err(Error::Io { .. })));
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn fetch_once() {
+        let cache = std::env::temp_dir().join("advent_of_code_fetch_once");
+        let _ = std::fs::remove_dir_all(&cache);
+        // Only serves one request, so fetching twice would fail if the input was not cached.
+        let (base_url, server) = stand_in::serve(vec![(200, "125 17\n")]);
+        let client = Client::new(&base_url, "token");
+
+        let path = cache.join("inputs/year_2024/day_11.txt");
+        assert_eq!(
+            Fetched::Downloaded(path.clone()),
+            fetch(&client, &cache, 2024, 11).unwrap()
+        );
+        assert_eq!(
+            Fetched::Cached(path.clone()),
+            fetch(&client, &cache, 2024, 11).unwrap()
+        );
+        assert_eq!("125 17\n", std::fs::read_to_string(path).unwrap());
+        assert_eq!(1, server.join().unwrap().len());
+
+        let (base_url, server) = stand_in::serve(vec![(404, "Not Found")]);
+        assert!(matches!(
+            fetch(&Client::new(&base_url, "token"), &cache, 2024, 12),
+            Err(Error::Fetch(_))
+        ));
+        assert!(!cache.join("inputs/year_2024/day_12.txt").exists());
+        server.join().unwrap();
+
+        std::fs::remove_dir_all(cache).unwrap();
+    }
+}
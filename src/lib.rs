@@ -0,0 +1,61 @@
+//! Solutions for the yearly Advent of Code puzzles, and the tooling to run, verify, and submit
+//! them.
+//!
+//! Every solution is registered in [`YEARS`], as an [`Entry`] holding a type-erased
+//! [`Solver`](solution::Solver), so that tools can run any day without knowing its types:
+//!
+//! ```
+//! use advent_of_code::solution::Part;
+//!
+//! let entry = advent_of_code::entry(2024, 1).unwrap();
+//! let parsed = entry.solver.parse("3   4\n4   3\n2   5\n1   3\n3   9\n3   3").unwrap();
+//!
+//! assert_eq!(Ok(11.into()), entry.solver.solve(Part::One, &parsed));
+//! ```
+//!
+//! Each day's module (e.g., [`year_2024::day_09`]) also exposes the types it is solved with (e.g.,
+//! [`Filesystem`](year_2024::day_09::filesystem::Filesystem)), for reuse elsewhere.
+
+#![warn(clippy::nursery, clippy::pedantic)]
+#![expect(
+    clippy::must_use_candidate,
+    clippy::return_self_not_must_use,
+    reason = "nearly every function is pure, so marking each one would only add noise"
+)]
+
+pub mod allocations;
+pub mod answers;
+pub mod bench;
+pub mod cancel;
+pub mod cli;
+pub mod client;
+pub mod error;
+pub mod history;
+pub mod input;
+#[cfg(test)]
+mod property;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+#[cfg(test)]
+mod snapshot;
+pub mod solution;
+pub mod submit;
+pub mod watch;
+pub mod year_2024;
+
+use runner::Entry;
+
+pub type Integer = u64;
+
+/// Every registered year, each with every day that can be run. Selected from by the
+/// command-line arguments.
+pub const YEARS: &[&[Entry]] = &[year_2024::DAYS];
+
+/// The registered solution to a day, if there is one.
+pub fn entry(year: u16, day: u8) -> Option<&'static Entry> {
+    YEARS
+        .iter()
+        .flat_map(|days| days.iter())
+        .find(|entry| entry.year() == year && entry.day() == day)
+}
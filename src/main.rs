@@ -1,38 +1,331 @@
 #![warn(clippy::nursery, clippy::pedantic)]
 
-mod year_2024;
-
-use paste::paste;
-
-type Integer = u64;
-
-fn main() {
-    macro_rules! days {
-        [ $( $day_str:expr, $day_num:ident; )+ ] => {
-            println!("Year 2024");
-
-            $(
-                println!(concat!("- Day ", $day_str));
-                paste! {
-                    println!("  - Part One: {}", year_2024::[<day $day_num>]::part_one());
-                    println!("  - Part Two: {}", year_2024::[<day $day_num>]::part_two())
-                }
-            );+
-        };
-    }
-
-    days![
-        "One", _01;
-        "Two", _02;
-        "Three", _03;
-        "Four", _04;
-        "Five", _05;
-        // "Six", _06;
-        // "Seven", _07;
-        "Eight", _08;
-        "Nine", _09;
-        "Ten", _10;
-        "Eleven", _11;
-        "Twelve", _12;
-    ];
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, SystemTime},
+};
+
+use advent_of_code::{
+    answers::{self, Answers},
+    cli::{
+        Cli, Command, ExamplesArgs, FetchArgs, NewArgs, PuzzleArgs, RunArgs, SubmitArgs, WatchArgs,
+    },
+    client::Client,
+    history::{self, History},
+    input::{self, Fetched},
+    report::Format,
+    runner::{self, Entry},
+    scaffold,
+    solution::{Answer, Part},
+    submit::{self, Known, Outcome, Submissions},
+    watch::Watch,
+    YEARS,
+};
+use clap::Parser;
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: advent_of_code::allocations::Counter = advent_of_code::allocations::Counter;
+
+fn main() -> ExitCode {
+    let command = Cli::parse()
+        .command
+        .unwrap_or_else(|| Command::Run(RunArgs::all()));
+
+    match command {
+        Command::Run(args) => run(&args),
+        Command::List => {
+            runner::list(YEARS);
+            ExitCode::SUCCESS
+        }
+        Command::New(args) => new(&args),
+        Command::Fetch(args) => fetch(&args),
+        Command::Submit(args) => submit(&args),
+        Command::Examples(args) => examples(&args),
+        Command::Watch(args) => watch(args),
+    }
+}
+
+/// The cache directory, printing an error if there is none.
+fn cache_directory() -> Option<PathBuf> {
+    let cache = input::cache_directory();
+
+    if cache.is_none() {
+        eprintln!(
+            "Error: no cache directory, set `{}` or `HOME`",
+            input::CACHE_ENV_VAR
+        );
+    }
+
+    cache
+}
+
+fn fetch(args: &FetchArgs) -> ExitCode {
+    let Some(cache) = cache_directory() else {
+        return ExitCode::FAILURE;
+    };
+    let client = Client::new(&args.client.base_url, &args.client.session);
+
+    match input::fetch(&client, &cache, args.puzzle.year, args.puzzle.day) {
+        Ok(Fetched::Cached(path)) => {
+            println!("Already cached at `{}`", path.display());
+            ExitCode::SUCCESS
+        }
+        Ok(Fetched::Downloaded(path)) => {
+            println!("Downloaded to `{}`", path.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new(args: &NewArgs) -> ExitCode {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    match scaffold::new_day(root, args.puzzle.year, args.puzzle.day, args.template) {
+        Ok(module) => {
+            println!("Created `{}`", module.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn examples(args: &ExamplesArgs) -> ExitCode {
+    let PuzzleArgs { year, day } = args.puzzle;
+    let module =
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("src/year_{year}/day_{day:02}/mod.rs"));
+
+    match scaffold::write_examples(&module, &args.page) {
+        Ok(examples) => {
+            for example in examples {
+                let answers: Vec<String> = example
+                    .answers
+                    .iter()
+                    .map(|(part, answer)| format!("Part {part}: {answer}"))
+                    .collect();
+                println!("Wrote `{}` ({})", example.name, answers.join(", "));
+            }
+            println!("Review the examples in `{}`", module.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn watch(args: WatchArgs) -> ExitCode {
+    let Some(year) = YEARS
+        .iter()
+        .flat_map(|days| days.iter())
+        .filter(|entry| {
+            entry.day() == args.day && args.year.is_none_or(|year| entry.year() == year)
+        })
+        .map(Entry::year)
+        .max()
+    else {
+        eprintln!("Error: day {} is not registered", args.day);
+        return ExitCode::FAILURE;
+    };
+
+    if args.input.as_deref().is_some_and(input::is_stdin) {
+        eprintln!(
+            "Error: `watch` re-reads its input on every change, so cannot read standard input"
+        );
+        return ExitCode::FAILURE;
+    }
+
+    // `cargo` runs in the package's directory, so relative paths would be resolved against it.
+    let input = match args.input.as_deref().map(std::fs::canonicalize).transpose() {
+        Ok(input) => input,
+        Err(err) => {
+            let path = args.input.unwrap_or_default();
+            eprintln!("Error: failed to access input `{}`: {err}", path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let watch = Watch {
+        year,
+        day: args.day,
+        input,
+        release: args.release,
+    };
+
+    let Err(err) = watch.run(Duration::from_millis(args.interval));
+    eprintln!("Error: failed to run `cargo`: {err}");
+    ExitCode::FAILURE
+}
+
+fn submit(args: &SubmitArgs) -> ExitCode {
+    let PuzzleArgs { year, day } = args.puzzle;
+    let Some(entry) = advent_of_code::entry(year, day) else {
+        eprintln!("Error: {year} day {day} is not registered");
+        return ExitCode::FAILURE;
+    };
+
+    let answer = match solve(entry, args.part, args.input.as_deref()) {
+        Ok(answer) => answer,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let Some(cache) = cache_directory() else {
+        return ExitCode::FAILURE;
+    };
+    let path = submit::path(&cache);
+    let mut submissions = match Submissions::load(&path) {
+        Ok(submissions) => submissions,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let client = Client::new(&args.client.base_url, &args.client.session);
+    let key = submit::Key {
+        year,
+        day,
+        part: args.part,
+    };
+    let outcome = submit::submit(
+        &client,
+        &mut submissions,
+        key,
+        answer.clone(),
+        SystemTime::now(),
+    )
+    .and_then(|outcome| submissions.save(&path).map(|()| outcome));
+
+    match outcome {
+        Ok(Outcome::Known(known)) => {
+            println!("Did not submit {answer}: {known}");
+
+            if known == Known::Solved(answer) {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Ok(Outcome::Cooldown(left)) => {
+            println!("Did not submit {answer}: wait {left:.0?} before submitting again");
+            ExitCode::FAILURE
+        }
+        Ok(Outcome::Submitted(response)) => {
+            println!("Submitted {answer}: {}", response.verdict);
+            if let Some(wait) = response.wait {
+                println!("Wait {wait:.0?} before submitting again");
+            }
+
+            if response.verdict == submit::Verdict::Right {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(err) => {
+            eprintln!("Error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Read the input of `entry`, then solve `part` of it.
+fn solve(entry: &Entry, part: Part, path: Option<&Path>) -> Result<Answer, String> {
+    let input = input::read(entry.year(), entry.day(), path, entry.embedded)
+        .map_err(|err| err.to_string())?;
+    let parsed = entry.solver.parse(&input).map_err(|err| err.to_string())?;
+
+    entry
+        .solver
+        .solve(part, &parsed)
+        .map_err(|err| err.to_string())
+}
+
+fn run(args: &RunArgs) -> ExitCode {
+    let days = match runner::select(YEARS, args) {
+        Ok(days) => days,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if args.bench {
+        return bench(&days, args);
+    }
+
+    if !args.verify && args.inputs.is_none() {
+        runner::run(&days, args, None);
+        return ExitCode::SUCCESS;
+    }
+
+    let path = args.answers.clone().unwrap_or_else(answers::default_path);
+    let mut answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let summary = match &args.inputs {
+        Some(directory) => runner::run_inputs(&days, args, directory, &mut answers),
+        None => runner::run(&days, args, Some(&mut answers)),
+    };
+    // Keep other formats free of anything but results.
+    if args.format == Format::Text {
+        println!("Verified: {summary}");
+    } else {
+        eprintln!("Verified: {summary}");
+    }
+
+    if args.record {
+        if let Err(err) = answers.save(&path) {
+            eprintln!("Error: {err}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if summary.failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn bench(days: &[&Entry], args: &RunArgs) -> ExitCode {
+    if args.no_history {
+        runner::bench(days, args, None);
+        return ExitCode::SUCCESS;
+    }
+
+    let path = args.history.clone().unwrap_or_else(history::default_path);
+    let mut history = match History::load(&path) {
+        Ok(history) => history,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    runner::bench(days, args, Some(&mut history));
+
+    if let Err(err) = history.save(&path) {
+        eprintln!("Error: {err}");
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,27 @@
+//! Shared configuration for property tests, which check that properties of the domain types (e.g.,
+//! that parsing and displaying round-trip) hold over many random values.
+//!
+//! Each type's generators live beside its tests, as functions returning a [`Strategy`], e.g.,
+//! `disk_map()`. Failing cases are shrunk to the smallest that still fails, then saved to
+//! `proptest-regressions/`, where they are rerun first until the failure is fixed.
+//!
+//! [`Strategy`]: proptest::strategy::Strategy
+
+use proptest::test_runner::Config;
+
+/// How many random cases each property is checked against, unless `PROPTEST_CASES` is set.
+pub const CASES: u32 = 2048;
+
+/// The configuration of every property test, with `#![proptest_config(property::config())]`.
+pub fn config() -> Config {
+    let default = Config::default();
+
+    Config {
+        cases: if std::env::var_os("PROPTEST_CASES").is_some() {
+            default.cases
+        } else {
+            CASES
+        },
+        ..default
+    }
+}
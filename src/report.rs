@@ -0,0 +1,512 @@
+//! Rendering the results of a run, either for people or for other tools.
+//!
+//! Results are reported one [`Row`] per part of each day, which [`Format::Json`] and
+//! [`Format::Csv`] render with the columns `year`, `day`, `part`, `answer`, `expected`,
+//! `status`, `parse_ns`, `solve_ns`, `error`, `allocations`, `allocated_bytes`, and `peak_bytes`.
+//! Columns without a value are `null` in JSON, and empty in CSV. The last three only have values
+//! with the `count-allocations` feature.
+
+use std::{
+    fmt::{Display, Write},
+    time::Duration,
+};
+
+use clap::ValueEnum;
+
+use crate::{
+    allocations::Allocations,
+    solution::{Answer, Part},
+};
+
+/// How the results of a run are printed.
+#[derive(Clone, Copy, Hash, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// A list of answers, grouped by year and day.
+    #[default]
+    Text,
+    /// An array of objects, one per line.
+    Json,
+    /// Comma-separated values, with a header.
+    Csv,
+}
+
+/// The result of a single part of a single day.
+#[derive(Clone, Hash, Debug, PartialEq, Eq)]
+pub struct Row {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub status: Status,
+    /// How long parsing took, if the input was parsed.
+    pub parse: Option<Duration>,
+    /// How long solving took, if the input was parsed.
+    pub solve: Option<Duration>,
+    /// What solving allocated, if the input was parsed and allocations were counted.
+    pub allocations: Option<Allocations>,
+}
+
+#[derive(Clone, Hash, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// When the part was solved, without being verified.
+    Solved,
+    /// When the answer matches the answers file.
+    Pass,
+    /// When the answer does not match the answers file.
+    Fail { expected: Answer },
+    /// When the answers file has no answer for this input.
+    Unknown,
+    /// When the answers file had no answer for this input, and the answer was recorded into it.
+    Recorded,
+    /// When the input could not be read or parsed, or the part could not be solved.
+    Error(String),
+    /// When parsing the input or solving the part panicked, with the panic's message.
+    Panicked(String),
+    /// When parsing the input or solving the part took longer than its time budget.
+    TimedOut,
+}
+
+impl Status {
+    const fn name(&self) -> &'static str {
+        match self {
+            Self::Solved => "solved",
+            Self::Pass => "pass",
+            Self::Fail { .. } => "fail",
+            Self::Unknown => "unknown",
+            Self::Recorded => "recorded",
+            Self::Error(_) => "error",
+            Self::Panicked(_) => "panicked",
+            Self::TimedOut => "timeout",
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fail { expected } => write!(f, "FAIL, expected {expected}"),
+            Self::Recorded => write!(f, "unknown, recorded"),
+            Self::Error(err) => write!(f, "Error: {err}"),
+            Self::Panicked(message) => write!(f, "PANICKED: {message}"),
+            Self::TimedOut => write!(f, "TIMED OUT"),
+            _ => write!(f, "{}", self.name()),
+        }
+    }
+}
+
+/// Renders [`Row`]s in a [`Format`], a day at a time, so that results can be printed as soon as
+/// each day is done.
+#[derive(Clone, Debug)]
+pub struct Report {
+    format: Format,
+    /// The year of the previous day, to print a header for each year as [`Format::Text`].
+    year: Option<u16>,
+    rows: usize,
+}
+
+impl Report {
+    pub const fn new(format: Format) -> Self {
+        Self {
+            format,
+            year: None,
+            rows: 0,
+        }
+    }
+
+    /// The output before any days.
+    pub const fn header(&self) -> &'static str {
+        match self.format {
+            Format::Text => "",
+            Format::Json => "[",
+            Format::Csv => {
+                "year,day,part,answer,expected,status,parse_ns,solve_ns,error,allocations,allocated_bytes,peak_bytes\n"
+            }
+        }
+    }
+
+    /// The output for the rows of a single day, in order of part.
+    pub fn day(&mut self, rows: &[Row]) -> String {
+        let output = match self.format {
+            Format::Text => self.text(rows),
+            Format::Json => rows
+                .iter()
+                .enumerate()
+                .map(|(index, row)| {
+                    let separator = if self.rows + index == 0 { "" } else { "," };
+                    [separator, "\n  ", &json(row)].concat()
+                })
+                .collect(),
+            Format::Csv => rows.iter().map(csv).collect(),
+        };
+        self.rows += rows.len();
+
+        output
+    }
+
+    /// The output after every day.
+    pub const fn footer(&self) -> &'static str {
+        match self.format {
+            Format::Text | Format::Csv => "",
+            Format::Json => "\n]\n",
+        }
+    }
+
+    fn text(&mut self, rows: &[Row]) -> String {
+        let Some(first) = rows.first() else {
+            return String::new();
+        };
+        let mut lines = vec![];
+
+        if self.year != Some(first.year) {
+            self.year = Some(first.year);
+            lines.push(format!("Year {}", first.year));
+        }
+
+        lines.push(format!("- Day {}", first.day));
+
+        match first.parse {
+            // Every part shares the same error when the input could not be read or parsed.
+            None => lines.push(format!("  - {}", first.status)),
+            Some(parse) => {
+                lines.push(format!("  - Parse: {parse:.2?}"));
+                lines.extend(rows.iter().map(|row| {
+                    let (Some(answer), Some(solve)) = (&row.answer, row.solve) else {
+                        return format!("  - Part {}: {}", row.part, row.status);
+                    };
+                    let details: Vec<String> = (row.status != Status::Solved)
+                        .then(|| row.status.to_string())
+                        .into_iter()
+                        .chain([format!("{solve:.2?}")])
+                        .chain(row.allocations.map(|allocations| allocations.to_string()))
+                        .collect();
+
+                    format!("  - Part {}: {answer} ({})", row.part, details.join(", "))
+                }));
+            }
+        }
+
+        lines
+            .iter()
+            .flat_map(|line| [line.as_str(), "\n"])
+            .collect()
+    }
+}
+
+/// A table of the answers to each of `parts` for each named input of a single day, as rows of that
+/// day, followed by the inputs with answers that panicked, timed out, or failed, if any.
+pub fn table(parts: &[Part], inputs: &[(String, Vec<Row>)]) -> String {
+    let header =
+        std::iter::once("Input".to_owned()).chain(parts.iter().map(|part| format!("Part {part}")));
+    let lines: Vec<Vec<String>> = std::iter::once(header.collect())
+        .chain(inputs.iter().map(|(name, rows)| {
+            std::iter::once(name.clone())
+                .chain(rows.iter().map(|row| match (&row.answer, &row.status) {
+                    (Some(answer), Status::Solved) => answer.to_string(),
+                    (Some(answer), status) => format!("{answer} ({status})"),
+                    (None, status) => status.to_string(),
+                }))
+                .collect()
+        }))
+        .collect();
+
+    let widths: Vec<usize> = (0..lines[0].len())
+        .map(|column| {
+            lines
+                .iter()
+                .filter_map(|line| line.get(column))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let mut output = String::new();
+    for line in &lines {
+        let cells: Vec<String> = line
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:width$}"))
+            .collect();
+        output.push_str("  ");
+        output.push_str(cells.join("  ").trim_end());
+        output.push('\n');
+    }
+
+    let flagged: Vec<&str> = inputs
+        .iter()
+        .filter(|(_, rows)| {
+            rows.iter().any(|row| {
+                matches!(
+                    row.status,
+                    Status::Fail { .. } | Status::Panicked(_) | Status::TimedOut
+                )
+            })
+        })
+        .map(|(name, _)| name.as_str())
+        .collect();
+    if !flagged.is_empty() {
+        output.push_str("  - Flagged: ");
+        output.push_str(&flagged.join(", "));
+        output.push('\n');
+    }
+
+    output
+}
+
+fn json(row: &Row) -> String {
+    let duration = |duration: Option<Duration>| {
+        duration.map_or_else(|| "null".to_owned(), |d| d.as_nanos().to_string())
+    };
+    let answer = |answer: Option<&Answer>| match answer {
+        Some(Answer::String(answer)) => json_string(answer),
+        Some(answer) => answer.to_string(),
+        None => "null".to_owned(),
+    };
+    let error = match &row.status {
+        Status::Error(err) | Status::Panicked(err) => json_string(err),
+        _ => "null".to_owned(),
+    };
+    let [allocations, allocated_bytes, peak_bytes] = allocation_columns(row)
+        .map(|column| column.map_or_else(|| "null".to_owned(), |value| value.to_string()));
+
+    format!(
+        r#"{{"year":{},"day":{},"part":{},"answer":{},"expected":{},"status":"{}","parse_ns":{},"solve_ns":{},"error":{},"allocations":{},"allocated_bytes":{},"peak_bytes":{}}}"#,
+        row.year,
+        row.day,
+        row.part.number(),
+        answer(row.answer.as_ref()),
+        answer(expected(row)),
+        row.status.name(),
+        duration(row.parse),
+        duration(row.solve),
+        error,
+        allocations,
+        allocated_bytes,
+        peak_bytes,
+    )
+}
+
+fn csv(row: &Row) -> String {
+    let duration = |duration: Option<Duration>| {
+        duration
+            .map(|d| d.as_nanos().to_string())
+            .unwrap_or_default()
+    };
+    let answer = |answer: Option<&Answer>| {
+        answer
+            .map(|answer| csv_field(&answer.to_string()))
+            .unwrap_or_default()
+    };
+    let error = match &row.status {
+        Status::Error(err) | Status::Panicked(err) => csv_field(err),
+        _ => String::new(),
+    };
+    let [allocations, allocated_bytes, peak_bytes] = allocation_columns(row)
+        .map(|column| column.map(|value| value.to_string()).unwrap_or_default());
+
+    format!(
+        "{},{},{},{},{},{},{},{},{},{},{},{}\n",
+        row.year,
+        row.day,
+        row.part.number(),
+        answer(row.answer.as_ref()),
+        answer(expected(row)),
+        row.status.name(),
+        duration(row.parse),
+        duration(row.solve),
+        error,
+        allocations,
+        allocated_bytes,
+        peak_bytes,
+    )
+}
+
+/// The values of the `allocations`, `allocated_bytes`, and `peak_bytes` columns.
+fn allocation_columns(row: &Row) -> [Option<u64>; 3] {
+    row.allocations.map_or([None; 3], |allocations| {
+        [
+            Some(allocations.count),
+            Some(allocations.bytes),
+            Some(allocations.peak),
+        ]
+    })
+}
+
+const fn expected(row: &Row) -> Option<&Answer> {
+    match &row.status {
+        Status::Fail { expected } => Some(expected),
+        _ => None,
+    }
+}
+
+/// A JSON string literal containing `s`.
+fn json_string(s: &str) -> String {
+    let mut output = String::with_capacity(s.len() + 2);
+    output.push('"');
+
+    for char in s.chars() {
+        match char {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            char if char.is_control() => {
+                write!(output, "\\u{:04x}", u32::from(char))
+                    .expect("writing to a `String` cannot fail");
+            }
+            char => output.push(char),
+        }
+    }
+
+    output.push('"');
+    output
+}
+
+/// A CSV field containing `s`, quoted only if necessary.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{table, Format, Report, Row, Status};
+    use crate::{allocations::Allocations, solution::Part};
+
+    fn rows() -> [Row; 3] {
+        let row = |part, answer, status| Row {
+            year: 2024,
+            day: 1,
+            part,
+            answer,
+            status,
+            parse: Some(Duration::from_micros(5)),
+            solve: Some(Duration::from_nanos(518)),
+            allocations: None,
+        };
+
+        [
+            Row {
+                allocations: Some(Allocations {
+                    count: 2,
+                    bytes: 2048,
+                    peak: 1024,
+                }),
+                ..row(
+                    Part::One,
+                    Some(11.into()),
+                    Status::Fail {
+                        expected: "1,2".into(),
+                    },
+                )
+            },
+            row(
+                Part::Two,
+                None,
+                Status::Error("not yet implemented, \"soon\"".to_owned()),
+            ),
+            Row {
+                day: 2,
+                parse: None,
+                solve: None,
+                allocations: None,
+                ..row(Part::One, None, Status::Error("no input".to_owned()))
+            },
+        ]
+    }
+
+    fn render(format: Format) -> String {
+        let [one, two, missing] = rows();
+        let mut report = Report::new(format);
+
+        [
+            report.header().to_owned(),
+            report.day(&[one, two]),
+            report.day(&[missing]),
+            report.footer().to_owned(),
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(
+            "Year 2024
+- Day 1
+  - Parse: 5.00µs
+  - Part One: 11 (FAIL, expected 1,2, 518.00ns, 2 allocations, 2.00 KiB, peak 1.00 KiB)
+  - Part Two: Error: not yet implemented, \"soon\"
+- Day 2
+  - Error: no input
+",
+            render(Format::Text)
+        );
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            r#"[
+  {"year":2024,"day":1,"part":1,"answer":11,"expected":"1,2","status":"fail","parse_ns":5000,"solve_ns":518,"error":null,"allocations":2,"allocated_bytes":2048,"peak_bytes":1024},
+  {"year":2024,"day":1,"part":2,"answer":null,"expected":null,"status":"error","parse_ns":5000,"solve_ns":518,"error":"not yet implemented, \"soon\"","allocations":null,"allocated_bytes":null,"peak_bytes":null},
+  {"year":2024,"day":2,"part":1,"answer":null,"expected":null,"status":"error","parse_ns":null,"solve_ns":null,"error":"no input","allocations":null,"allocated_bytes":null,"peak_bytes":null}
+]
+"#,
+            render(Format::Json)
+        );
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            r#"year,day,part,answer,expected,status,parse_ns,solve_ns,error,allocations,allocated_bytes,peak_bytes
+2024,1,1,11,"1,2",fail,5000,518,,2,2048,1024
+2024,1,2,,,error,5000,518,"not yet implemented, ""soon""",,,
+2024,2,1,,,error,,,no input,,,
+"#,
+            render(Format::Csv)
+        );
+    }
+
+    #[test]
+    fn tables() {
+        let [one, two, _] = rows();
+        let pass = Row {
+            status: Status::Pass,
+            ..one.clone()
+        };
+        let panicked = Row {
+            answer: None,
+            status: Status::Panicked("oops".to_owned()),
+            ..two
+        };
+        let timed_out = Row {
+            answer: None,
+            status: Status::TimedOut,
+            ..one
+        };
+
+        assert_eq!(
+            "  Input  Part One                 Part Two
+  alice  11 (pass)                Error: not yet implemented, \"soon\"
+  bob    11 (FAIL, expected 1,2)  PANICKED: oops
+  carol  TIMED OUT                11 (pass)
+  - Flagged: bob, carol
+",
+            table(
+                &[Part::One, Part::Two],
+                &[
+                    ("alice".to_owned(), vec![pass.clone(), two]),
+                    ("bob".to_owned(), vec![one, panicked]),
+                    ("carol".to_owned(), vec![timed_out, pass]),
+                ]
+            )
+        );
+    }
+}
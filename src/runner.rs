@@ -0,0 +1,1315 @@
+use std::{
+    any::Any,
+    cell::Cell,
+    fmt::Display,
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError, Sender},
+        Once,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::{
+    allocations::{self, Allocations},
+    answers::{self, Answers, Key, Verdict},
+    bench::{time, Stats, Timings},
+    cancel::{self, Token},
+    cli::RunArgs,
+    error::{Error, Result},
+    history::{self, History, Record},
+    input,
+    report::{self, Format, Report, Row, Status},
+    solution::{Answer, Parsed, Part, Solver},
+};
+
+/// A registered [`Solver`], alongside its puzzle input if it was embedded into the binary.
+pub struct Entry {
+    pub solver: &'static (dyn Solver + Sync),
+    pub embedded: Option<&'static str>,
+}
+
+impl Entry {
+    pub fn year(&self) -> u16 {
+        self.solver.year()
+    }
+
+    pub fn day(&self) -> u8 {
+        self.solver.day()
+    }
+}
+
+/// The contents of a day's `data.txt`, if inputs are being embedded into the binary.
+#[cfg(feature = "embed-inputs")]
+macro_rules! embedded {
+    ($year:ident, $day:ident) => {
+        Some(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/",
+            stringify!($year),
+            "/",
+            stringify!($day),
+            "/data.txt",
+        )))
+    };
+}
+#[cfg(not(feature = "embed-inputs"))]
+macro_rules! embedded {
+    ($year:ident, $day:ident) => {
+        None
+    };
+}
+pub(crate) use embedded;
+
+/// Build the [`Entry`]s for a year's days, given the name of the year's module and its day
+/// modules. Expects to be invoked in the year's module. E.g.,
+///
+/// ```ignore
+/// pub const DAYS: &[Entry] = days![
+///     year_2024;
+///     day_01;
+///     day_02;
+/// ];
+/// ```
+macro_rules! days {
+    [ $year:ident; $( $day:ident; )+ ] => {
+        &[
+            $(
+                $crate::runner::Entry {
+                    solver: &$day::Day,
+                    embedded: $crate::runner::embedded!($year, $day),
+                }
+            ),+
+        ]
+    };
+}
+pub(crate) use days;
+
+/// Select the registered days that match `args`, in calendar order.
+///
+/// # Errors
+///
+/// Returns [`SelectError::NoMatches`] if no days match `args`, or
+/// [`SelectError::InputForMultipleDays`] if an input path was given for more than one day.
+pub fn select<'a>(years: &[&'a [Entry]], args: &RunArgs) -> Result<Vec<&'a Entry>, SelectError> {
+    let days = || years.iter().flat_map(|days| days.iter());
+
+    let year = args
+        .year
+        .or_else(|| days().map(Entry::year).max())
+        .ok_or(SelectError::NoMatches)?;
+
+    let mut selected: Vec<&Entry> = days()
+        .filter(|entry| (args.all_days() && args.year.is_none()) || entry.year() == year)
+        .filter(|entry| {
+            args.day
+                .as_ref()
+                .is_none_or(|days| days.contains(entry.day()))
+        })
+        .collect();
+    selected.sort_by_key(|entry| (entry.year(), entry.day()));
+
+    // Without `--day`, `--all`, or `--verify`, only run the latest day.
+    if !args.all_days() && args.day.is_none() {
+        selected = selected.pop().into_iter().collect();
+    }
+
+    match selected.len() {
+        0 => Err(SelectError::NoMatches),
+        2.. if args.input.is_some() => Err(SelectError::InputForMultipleDays),
+        _ => Ok(selected),
+    }
+}
+
+/// Print every registered day, grouped by year, in calendar order.
+pub fn list(years: &[&[Entry]]) {
+    let mut days: Vec<&Entry> = years.iter().flat_map(|days| days.iter()).collect();
+    days.sort_by_key(|entry| (entry.year(), entry.day()));
+
+    let mut year = None;
+
+    for entry in days {
+        if year != Some(entry.year()) {
+            year = Some(entry.year());
+            println!("Year {}", entry.year());
+        }
+
+        println!("- Day {}", entry.day());
+    }
+}
+
+/// Run the selected days, printing their answers and how long parsing and each part took.
+///
+/// Results are printed in the [`Format`] given by `--format`.
+///
+/// With `--jobs`, parts are solved concurrently, but still printed in calendar order once every
+/// day is solved. Each part then parses its own copy of the input, as parsed inputs cannot be
+/// shared between threads.
+///
+/// With `answers` (i.e., `--verify`), each answer is checked against it and its [`Status`] is
+/// printed. With `--record`, unknown answers are inserted into `answers`.
+///
+/// # Panics
+///
+/// Panics if a solution panics.
+pub fn run(days: &[&Entry], args: &RunArgs, mut answers: Option<&mut Answers>) -> Summary {
+    let start = Instant::now();
+    let parts = args.parts();
+    let timeout = args.timeout();
+    let inputs: Vec<_> = days
+        .iter()
+        .map(|entry| {
+            input::read(
+                entry.year(),
+                entry.day(),
+                args.input.as_deref(),
+                entry.embedded,
+            )
+        })
+        .collect();
+
+    let jobs = args.jobs();
+    let mut solved_in_parallel =
+        (jobs.get() > 1).then(|| solve_parallel(days, &inputs, &parts, jobs, timeout));
+
+    let mut summary = Summary::default();
+    let mut report = Report::new(args.format);
+    print!("{}", report.header());
+
+    for (index, (entry, input)) in days.iter().zip(&inputs).enumerate() {
+        let solved = input
+            .as_deref()
+            .map_err(|err| Status::Error(err.to_string()))
+            .and_then(|input| {
+                let solved = solved_in_parallel.as_mut().map_or_else(
+                    || solve(entry, input, &parts, timeout),
+                    |solved| {
+                        solved[index]
+                            .take()
+                            .expect("every readable input is solved")
+                    },
+                );
+
+                solved.map(|solved| (input, solved)).map_err(|err| {
+                    parts.iter().for_each(|_| summary.add_error(err));
+                    error_status(err)
+                })
+            });
+
+        let rows: Vec<Row> = match solved {
+            Ok((input, solved)) => solved
+                .parts
+                .iter()
+                .map(|part| {
+                    part_row(
+                        entry,
+                        input,
+                        solved.parse,
+                        part,
+                        answers.as_deref_mut(),
+                        args.record,
+                        &mut summary,
+                    )
+                })
+                .collect(),
+            Err(status) => parts
+                .iter()
+                .map(|&part| Row {
+                    year: entry.year(),
+                    day: entry.day(),
+                    part,
+                    answer: None,
+                    status: status.clone(),
+                    parse: None,
+                    solve: None,
+                    allocations: None,
+                })
+                .collect(),
+        };
+        print!("{}", report.day(&rows));
+    }
+
+    print!("{}", report.footer());
+    if args.format == Format::Text {
+        println!("Finished in {:.2?}", start.elapsed());
+    }
+
+    summary
+}
+
+/// The [`Row`] of a part whose input was parsed, verifying its answer if `answers` is provided.
+fn part_row(
+    entry: &Entry,
+    input: &str,
+    parse: Duration,
+    part: &SolvedPart,
+    answers: Option<&mut Answers>,
+    record: bool,
+    summary: &mut Summary,
+) -> Row {
+    let row = |answer, status| Row {
+        year: entry.year(),
+        day: entry.day(),
+        part: part.part,
+        answer,
+        status,
+        parse: Some(parse),
+        solve: Some(part.duration),
+        allocations: part.allocations,
+    };
+
+    let answer = match &part.answer {
+        Ok(answer) => answer.clone(),
+        Err(err) => {
+            summary.add_error(*err);
+            return row(None, error_status(*err));
+        }
+    };
+
+    let Some(answers) = answers else {
+        return row(Some(answer), Status::Solved);
+    };
+
+    let key = Key {
+        year: entry.year(),
+        day: entry.day(),
+        part: part.part,
+        input: answers::hash_input(input),
+    };
+
+    let status = verify(answers, key, &answer, record, summary);
+    row(Some(answer), status)
+}
+
+/// Run each of `days` against every one of its named inputs in `directory` (see
+/// [`input::named`]), printing a table of answers per day, with a row per input.
+///
+/// Each answer is checked against `answers`, and with `--record`, unknown answers are inserted
+/// into it. Panics are caught and reported in the table, with each counted as a failure. Each part
+/// parses the input for itself, so that a panic or timeout affects only that part.
+pub fn run_inputs(
+    days: &[&Entry],
+    args: &RunArgs,
+    directory: &Path,
+    answers: &mut Answers,
+) -> Summary {
+    let parts = args.parts();
+    let mut summary = Summary::default();
+    let mut year = None;
+
+    for entry in days {
+        if year != Some(entry.year()) {
+            year = Some(entry.year());
+            println!("Year {}", entry.year());
+        }
+        println!("- Day {}", entry.day());
+
+        let inputs = match input::named(directory, entry.year(), entry.day()) {
+            Ok(inputs) if inputs.is_empty() => {
+                let directory = input::named_directory(directory, entry.year(), entry.day());
+                println!("  - Error: no inputs in `{}`", directory.display());
+                continue;
+            }
+            Ok(inputs) => inputs,
+            Err(err) => {
+                println!("  - Error: {err}");
+                continue;
+            }
+        };
+
+        let rows: Vec<(String, Vec<Row>)> = inputs
+            .into_iter()
+            .map(|(name, path)| {
+                let rows = input_rows(
+                    entry,
+                    &path,
+                    &parts,
+                    args.timeout(),
+                    answers,
+                    args.record,
+                    &mut summary,
+                );
+                (name, rows)
+            })
+            .collect();
+        print!("{}", report::table(&parts, &rows));
+    }
+
+    summary
+}
+
+/// The rows of solving each of `parts` of `entry` with the input at `path`, catching panics.
+fn input_rows(
+    entry: &Entry,
+    path: &Path,
+    parts: &[Part],
+    timeout: Option<Duration>,
+    answers: &mut Answers,
+    record: bool,
+    summary: &mut Summary,
+) -> Vec<Row> {
+    let row = |part, status| Row {
+        year: entry.year(),
+        day: entry.day(),
+        part,
+        answer: None,
+        status,
+        parse: None,
+        solve: None,
+        allocations: None,
+    };
+
+    let input = match input::read(entry.year(), entry.day(), Some(path), None) {
+        Ok(input) => input,
+        Err(err) => {
+            return parts
+                .iter()
+                .map(|&part| row(part, Status::Error(err.to_string())))
+                .collect();
+        }
+    };
+
+    parts
+        .iter()
+        .map(|&part| {
+            let solved = {
+                // Panics are reported in the table, instead of as they happen.
+                let _silenced = Silenced::new();
+                panic::catch_unwind(AssertUnwindSafe(|| solve(entry, &input, &[part], timeout)))
+            };
+
+            match solved {
+                Ok(Ok(solved)) => part_row(
+                    entry,
+                    &input,
+                    solved.parse,
+                    &solved.parts[0],
+                    Some(&mut *answers),
+                    record,
+                    summary,
+                ),
+                Ok(Err(err)) => {
+                    summary.add_error(err);
+                    row(part, error_status(err))
+                }
+                Err(payload) => {
+                    summary.failed += 1;
+                    row(part, Status::Panicked(panic_message(&*payload)))
+                }
+            }
+        })
+        .collect()
+}
+
+thread_local! {
+    /// Whether panics on this thread are silenced, by a [`Silenced`].
+    static SILENCED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Silences panics on this thread until dropped, leaving other threads' panics alone.
+///
+/// The first creates a panic hook that skips silenced threads, calling the previous hook for the
+/// rest, which is then left in place. Guards only restore this thread's previous state, so they
+/// can overlap in any order, on any number of threads.
+struct Silenced {
+    previous: bool,
+}
+
+impl Silenced {
+    fn new() -> Self {
+        static HOOK: Once = Once::new();
+        HOOK.call_once(|| {
+            let hook = panic::take_hook();
+            panic::set_hook(Box::new(move |info| {
+                // Fails while the thread is being torn down, when it cannot have been silenced.
+                if !SILENCED.try_with(Cell::get).unwrap_or_default() {
+                    hook(info);
+                }
+            }));
+        });
+
+        Self {
+            previous: SILENCED.replace(true),
+        }
+    }
+}
+
+impl Drop for Silenced {
+    fn drop(&mut self) {
+        SILENCED.set(self.previous);
+    }
+}
+
+/// The [`Status`] of a part that could not be solved because of `err`.
+fn error_status(err: Error) -> Status {
+    match err {
+        Error::TimedOut => Status::TimedOut,
+        err => Status::Error(err.to_string()),
+    }
+}
+
+/// The message of a caught panic, if it has one.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_owned())
+}
+
+/// Check `answer` against `answers`, adding its [`Verdict`] to `summary`, and recording it if it
+/// is unknown and `record` is set.
+fn verify(
+    answers: &mut Answers,
+    key: Key,
+    answer: &Answer,
+    record: bool,
+    summary: &mut Summary,
+) -> Status {
+    let verdict = answers.verify(&key, answer);
+    summary.add(&verdict);
+
+    match verdict {
+        Verdict::Pass => Status::Pass,
+        Verdict::Fail { expected } => Status::Fail { expected },
+        Verdict::Unknown if record => {
+            answers.insert(key, answer.clone());
+            Status::Recorded
+        }
+        Verdict::Unknown => Status::Unknown,
+    }
+}
+
+/// Run each day repeatedly, printing timing statistics for parsing and each part, alongside a
+/// total for each year.
+///
+/// With `history`, each part's median time is recorded into it, and parts slower than their
+/// [`baseline`](History::baseline) by more than `--threshold` are flagged.
+pub fn bench(days: &[&Entry], args: &RunArgs, mut history: Option<&mut History>) {
+    let commit = history.is_some().then(history::commit).flatten();
+    let timestamp = history::timestamp();
+    let mut year = None;
+    // The total time of each benchmark run, summed across every day of the current year.
+    let mut year_totals: Option<Box<[Duration]>> = None;
+
+    for entry in days {
+        if year != Some(entry.year()) {
+            print_year_total(year_totals.take().as_deref());
+            year = Some(entry.year());
+            println!("Year {}", entry.year());
+        }
+
+        println!("- Day {}", entry.day());
+
+        let input = match input::read(
+            entry.year(),
+            entry.day(),
+            args.input.as_deref(),
+            entry.embedded,
+        ) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("  - Error: {err}");
+                continue;
+            }
+        };
+
+        let timings = match Timings::new(entry.solver, &input, &args.parts(), args.runs.get()) {
+            Ok(timings) => timings,
+            Err(err) => {
+                println!("  - Error: {err}");
+                continue;
+            }
+        };
+        print_stats("Parse", &timings.parse);
+        for part in &timings.parts {
+            let answer = match &part.answer {
+                Ok(answer) => answer,
+                Err(err) => {
+                    println!("  - Part {}: Error: {err}", part.part);
+                    continue;
+                }
+            };
+            print_stats(&format!("Part {} ({answer})", part.part), &part.durations);
+
+            let (Some(history), Some(stats)) =
+                (history.as_deref_mut(), Stats::new(&part.durations))
+            else {
+                continue;
+            };
+            let record = Record {
+                timestamp,
+                commit: commit.clone(),
+                key: Key {
+                    year: entry.year(),
+                    day: entry.day(),
+                    part: part.part,
+                    input: answers::hash_input(&input),
+                },
+                duration: stats.median,
+            };
+            if let Some(regression) = history.push(record, args.threshold) {
+                println!("    - {regression}");
+            }
+        }
+
+        let totals = timings.totals();
+        year_totals = Some(match year_totals {
+            Some(year_totals) => year_totals
+                .iter()
+                .zip(&totals)
+                .map(|(year_total, total)| *year_total + *total)
+                .collect(),
+            None => totals,
+        });
+    }
+
+    print_year_total(year_totals.as_deref());
+}
+
+/// How long a day took to parse, and the answer to each of its parts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Solved {
+    parse: Duration,
+    parts: Vec<SolvedPart>,
+}
+
+/// The answer to a single part, how long it took to solve, and what it allocated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct SolvedPart {
+    part: Part,
+    answer: Result<Answer>,
+    duration: Duration,
+    allocations: Option<Allocations>,
+}
+
+/// Parse `input` once, then solve each of `parts` with it, within `timeout` if provided (see
+/// [`solve_within`]).
+///
+/// # Errors
+///
+/// Returns an error if `input` could not be parsed.
+///
+/// # Panics
+///
+/// Panics if the solution panics.
+fn solve(entry: &Entry, input: &str, parts: &[Part], timeout: Option<Duration>) -> Result<Solved> {
+    if let Some(timeout) = timeout {
+        return solve_within(entry.solver, input, parts, timeout);
+    }
+
+    let (parsed, parse) = time(|| entry.solver.parse(input));
+    let parsed = parsed?;
+
+    let parts = parts
+        .iter()
+        .map(|&part| solve_part(entry.solver, part, &parsed))
+        .collect();
+
+    Ok(Solved { parse, parts })
+}
+
+fn solve_part(solver: &dyn Solver, part: Part, parsed: &Parsed) -> SolvedPart {
+    let ((answer, duration), allocations) =
+        allocations::measure(|| time(|| solver.solve(part, parsed)));
+
+    SolvedPart {
+        part,
+        answer,
+        duration,
+        allocations,
+    }
+}
+
+/// How long a cancelled part is given to stop, before it is abandoned.
+const GRACE: Duration = Duration::from_millis(100);
+
+/// What a thread solving a day for [`solve_within`] has finished.
+enum Progress {
+    Parsed(Result<Duration>),
+    Solved(SolvedPart),
+}
+
+/// [`solve`] on another thread, cancelling parsing or any part that takes longer than `timeout`
+/// (see [`cancel`]), which is then [`Error::TimedOut`].
+///
+/// A part that does not stop within [`GRACE`] of being cancelled is left running in the
+/// background, and the remaining parts are solved on a new thread, parsing the input again. If
+/// that fails, each remaining part fails with it.
+///
+/// # Errors
+///
+/// Returns an error if `input` could not be parsed in time before any part was solved.
+///
+/// # Panics
+///
+/// Panics if the solution panics.
+fn solve_within(
+    solver: &'static (dyn Solver + Sync),
+    input: &str,
+    parts: &[Part],
+    timeout: Duration,
+) -> Result<Solved> {
+    let mut parse = None;
+    let mut finished: Vec<SolvedPart> = Vec::with_capacity(parts.len());
+
+    while let Some(remaining) = parts
+        .get(finished.len()..)
+        .filter(|parts| !parts.is_empty())
+    {
+        // One for parsing, then one for each part.
+        let tokens: Vec<Token> = (0..=remaining.len()).map(|_| Token::default()).collect();
+        let (sender, receiver) = mpsc::channel();
+        let worker = {
+            let (input, remaining, tokens) = (input.to_owned(), remaining.to_vec(), tokens.clone());
+            // The worker's panics are resumed on this thread, so are silenced if this thread is.
+            let silenced = SILENCED.get();
+            std::thread::spawn(move || {
+                let _silenced = silenced.then(Silenced::new);
+                solve_worker(solver, &input, &remaining, &tokens, &sender);
+            })
+        };
+
+        // Which of `tokens` the worker is running under.
+        let mut step = 0;
+        let mut deadline = Instant::now() + timeout;
+
+        loop {
+            match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                Ok(Progress::Parsed(Ok(duration))) => {
+                    parse.get_or_insert(duration);
+                }
+                Ok(Progress::Parsed(Err(err))) if finished.is_empty() => return Err(err),
+                Ok(Progress::Solved(part)) => finished.push(part),
+                Err(RecvTimeoutError::Disconnected) => {
+                    if let Err(payload) = worker.join() {
+                        panic::resume_unwind(payload);
+                    }
+                    break;
+                }
+                Err(RecvTimeoutError::Timeout) if !tokens[step].is_cancelled() => {
+                    tokens[step].cancel();
+                    deadline = Instant::now() + GRACE;
+                    continue;
+                }
+                Err(RecvTimeoutError::Timeout) if step == 0 && finished.is_empty() => {
+                    return Err(Error::TimedOut);
+                }
+                // Parsing again after abandoning a part failed, which loses none of the parts
+                // before it.
+                Ok(Progress::Parsed(Err(err))) => {
+                    finished.extend(unsolved(remaining, err, timeout + GRACE));
+                    break;
+                }
+                Err(RecvTimeoutError::Timeout) if step == 0 => {
+                    finished.extend(unsolved(remaining, Error::TimedOut, timeout + GRACE));
+                    break;
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    finished.push(SolvedPart {
+                        part: remaining[step - 1],
+                        answer: Err(Error::TimedOut),
+                        duration: timeout + GRACE,
+                        allocations: None,
+                    });
+                    break;
+                }
+            }
+
+            step += 1;
+            deadline = Instant::now() + timeout;
+        }
+    }
+
+    Ok(Solved {
+        parse: parse.unwrap_or_default(),
+        parts: finished,
+    })
+}
+
+/// Each of `parts` failed with `err`, after `duration`.
+fn unsolved(
+    parts: &[Part],
+    err: Error,
+    duration: Duration,
+) -> impl Iterator<Item = SolvedPart> + '_ {
+    parts.iter().map(move |&part| SolvedPart {
+        part,
+        answer: Err(err),
+        duration,
+        allocations: None,
+    })
+}
+
+/// Parse `input`, then solve each of `parts`, each under the next of `tokens`, sending `progress`
+/// after each. Anything that finishes after being cancelled is [`Error::TimedOut`].
+fn solve_worker(
+    solver: &dyn Solver,
+    input: &str,
+    parts: &[Part],
+    tokens: &[Token],
+    progress: &Sender<Progress>,
+) {
+    let (parsed, parse) = cancel::with(&tokens[0], || time(|| solver.parse(input)));
+    let parsed = match parsed {
+        Ok(_) if tokens[0].is_cancelled() => Err(Error::TimedOut),
+        parsed => parsed,
+    };
+    let parsed = match parsed {
+        // Once a step is abandoned, nothing is listening, so the rest can be skipped.
+        Ok(parsed) if progress.send(Progress::Parsed(Ok(parse))).is_ok() => parsed,
+        Ok(_) => return,
+        Err(err) => {
+            let _ = progress.send(Progress::Parsed(Err(err)));
+            return;
+        }
+    };
+
+    for (&part, token) in parts.iter().zip(&tokens[1..]) {
+        let mut finished = cancel::with(token, || solve_part(solver, part, &parsed));
+        if token.is_cancelled() {
+            finished.answer = Err(Error::TimedOut);
+        }
+
+        if progress.send(Progress::Solved(finished)).is_err() {
+            return;
+        }
+    }
+}
+
+/// [`solve`] each day whose input could be read, with each of `parts` solved separately across
+/// up to `jobs` threads.
+///
+/// Returns one item per day, which is [`None`] if its input could not be read. Its parse time is
+/// that of its first part.
+fn solve_parallel(
+    days: &[&Entry],
+    inputs: &[Result<String, input::Error>],
+    parts: &[Part],
+    jobs: NonZeroUsize,
+    timeout: Option<Duration>,
+) -> Vec<Option<Result<Solved>>> {
+    let tasks: Vec<(usize, &str, Part)> = inputs
+        .iter()
+        .enumerate()
+        .filter_map(|(index, input)| Some((index, input.as_deref().ok()?)))
+        .flat_map(|(index, input)| parts.iter().map(move |&part| (index, input, part)))
+        .collect();
+    let next = AtomicUsize::new(0);
+
+    let mut solved: Vec<(usize, Result<Solved>)> = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.get().min(tasks.len()))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut solved = vec![];
+
+                    while let Some(&(index, input, part)) =
+                        tasks.get(next.fetch_add(1, Ordering::Relaxed))
+                    {
+                        solved.push((index, solve(days[index], input, &[part], timeout)));
+                    }
+
+                    solved
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| {
+                worker
+                    .join()
+                    .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+            })
+            .collect()
+    });
+    // Sorting is stable, so each day's parts stay in order.
+    solved.sort_by_key(|&(index, _)| index);
+
+    let mut by_day: Vec<Option<Result<Solved>>> = inputs.iter().map(|_| None).collect();
+
+    for (index, part) in solved {
+        by_day[index] = Some(match (by_day[index].take(), part) {
+            (None, part) => part,
+            (Some(Ok(mut day)), Ok(part)) => {
+                day.parts.extend(part.parts);
+                Ok(day)
+            }
+            (Some(Err(err)), _) | (Some(Ok(_)), Err(err)) => Err(err),
+        });
+    }
+
+    by_day
+}
+
+/// The number of each [`Verdict`] given while verifying, with errors counted as failures.
+#[derive(Clone, Copy, Hash, Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub passed: usize,
+    pub failed: usize,
+    pub unknown: usize,
+}
+
+impl Summary {
+    const fn add(&mut self, verdict: &Verdict) {
+        match verdict {
+            Verdict::Pass => self.passed += 1,
+            Verdict::Fail { .. } => self.failed += 1,
+            Verdict::Unknown => self.unknown += 1,
+        }
+    }
+
+    /// Unimplemented parts are unknown, rather than failures.
+    const fn add_error(&mut self, err: Error) {
+        match err {
+            Error::Unimplemented => self.unknown += 1,
+            _ => self.failed += 1,
+        }
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, {} unknown",
+            self.passed, self.failed, self.unknown
+        )
+    }
+}
+
+fn print_stats(label: &str, durations: &[Duration]) {
+    if let Some(stats) = Stats::new(durations) {
+        println!("  - {label}: {stats}");
+    }
+}
+
+fn print_year_total(totals: Option<&[Duration]>) {
+    if let Some(stats) = totals.and_then(Stats::new) {
+        println!("- Total: {stats}");
+    }
+}
+
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq)]
+pub enum SelectError {
+    /// When no registered days match the selection.
+    NoMatches,
+    /// When an input path is provided, but more than one day is selected.
+    InputForMultipleDays,
+}
+
+impl Display for SelectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoMatches => write!(f, "no registered days match the selection"),
+            Self::InputForMultipleDays => {
+                write!(f, "`--input` can only be used when selecting a single day")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SelectError {}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        panic,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Condvar, Mutex,
+        },
+        time::Duration,
+    };
+
+    use super::{
+        input_rows, select, solve, solve_parallel, Entry, SelectError, Silenced, Summary, SILENCED,
+    };
+    use crate::{
+        answers::{self, Answers, Key},
+        cancel,
+        cli::RunArgs,
+        error::{Error, Result},
+        input,
+        report::Status,
+        solution::{Answer, Part, Solution},
+        year_2024,
+    };
+
+    /// A stand-in for a solution from another year.
+    struct Day2023;
+
+    impl Solution for Day2023 {
+        type Input = ();
+
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 1;
+
+        fn parse(_input: &str) -> Result<Self::Input> {
+            Ok(())
+        }
+
+        fn part_one((): &Self::Input) -> Result<Answer> {
+            Ok(0.into())
+        }
+
+        fn part_two((): &Self::Input) -> Result<Answer> {
+            Ok(0.into())
+        }
+    }
+
+    /// A stand-in for a solution that panics on long inputs.
+    struct Panics;
+
+    impl Solution for Panics {
+        type Input = usize;
+
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 2;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.len())
+        }
+
+        fn part_one(input: &Self::Input) -> Result<Answer> {
+            Ok((*input).into())
+        }
+
+        fn part_two(input: &Self::Input) -> Result<Answer> {
+            assert!(*input < 5, "input is too long");
+            Ok(0.into())
+        }
+    }
+
+    /// A stand-in for a solution that runs forever on some inputs, either checking whether it has
+    /// been cancelled or not. One that does not check runs until [`RELEASE`] is set.
+    struct Slow;
+
+    /// Whether parts of [`Slow`] that do not check whether they have been cancelled can finish.
+    static RELEASE: (Mutex<bool>, Condvar) = (Mutex::new(false), Condvar::new());
+
+    /// Whether [`Slow`] has parsed `reparse` before, after which parsing it runs forever.
+    static REPARSED: AtomicBool = AtomicBool::new(false);
+
+    impl Solution for Slow {
+        type Input = String;
+
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 3;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            if input == "parse" || input == "reparse" && REPARSED.swap(true, Ordering::Relaxed) {
+                loop {
+                    cancel::check()?;
+                }
+            }
+            Ok(input.to_owned())
+        }
+
+        fn part_one(input: &Self::Input) -> Result<Answer> {
+            match input.as_str() {
+                "check" => loop {
+                    cancel::check()?;
+                },
+                "hang" | "reparse" => {
+                    let (released, condvar) = &RELEASE;
+                    let _released = condvar
+                        .wait_while(released.lock().unwrap(), |released| !*released)
+                        .unwrap();
+                    Ok(1.into())
+                }
+                _ => Ok(1.into()),
+            }
+        }
+
+        fn part_two(_input: &Self::Input) -> Result<Answer> {
+            Ok(2.into())
+        }
+    }
+
+    const YEAR_2023: &[Entry] = &[Entry {
+        solver: &Day2023,
+        embedded: None,
+    }];
+
+    const DAYS: &[Entry] = &[
+        Entry {
+            solver: &year_2024::day_09::Day,
+            embedded: None,
+        },
+        Entry {
+            solver: &year_2024::day_01::Day,
+            embedded: None,
+        },
+        Entry {
+            solver: &year_2024::day_04::Day,
+            embedded: None,
+        },
+    ];
+
+    fn days(args: &RunArgs) -> Result<Vec<u8>, SelectError> {
+        Ok(select(&[DAYS], args)?
+            .iter()
+            .map(|entry| entry.day())
+            .collect())
+    }
+
+    #[test]
+    fn select_days() {
+        assert_eq!(Ok(vec![9]), days(&RunArgs::default()));
+        assert_eq!(Ok(vec![1, 4, 9]), days(&RunArgs::all()));
+        assert_eq!(
+            Ok(vec![1, 4]),
+            days(&RunArgs {
+                day: Some("..=5".parse().unwrap()),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            Err(SelectError::NoMatches),
+            days(&RunArgs {
+                year: Some(2023),
+                ..RunArgs::all()
+            })
+        );
+        assert_eq!(
+            Err(SelectError::InputForMultipleDays),
+            days(&RunArgs {
+                input: Some("data.txt".into()),
+                ..RunArgs::all()
+            })
+        );
+    }
+    #[test]
+    fn select_years() {
+        let days = |args: &RunArgs| -> Result<Vec<(u16, u8)>, SelectError> {
+            Ok(select(&[DAYS, YEAR_2023], args)?
+                .iter()
+                .map(|entry| (entry.year(), entry.day()))
+                .collect())
+        };
+
+        assert_eq!(Ok(vec![(2024, 9)]), days(&RunArgs::default()));
+        assert_eq!(
+            Ok(vec![(2023, 1)]),
+            days(&RunArgs {
+                year: Some(2023),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            Ok(vec![(2023, 1), (2024, 1), (2024, 4), (2024, 9)]),
+            days(&RunArgs::all())
+        );
+    }
+
+    #[test]
+    fn solve_in_parallel() {
+        let days: Vec<&Entry> = DAYS.iter().collect();
+        let inputs = [
+            Ok("2333133121414131402".to_owned()),
+            Ok("3   4\n4   3\n2   5\n1   3\n3   9\n3   3".to_owned()),
+            Err(input::Error::Missing {
+                year: 2024,
+                day: 4,
+                searched: Box::new([]),
+            }),
+        ];
+        let answers = |day: Option<Result<super::Solved>>| {
+            day.map(|day| {
+                day.map(|day| {
+                    day.parts
+                        .into_iter()
+                        .map(|part| (part.part, part.answer))
+                        .collect::<Vec<_>>()
+                })
+            })
+        };
+
+        let solved: Vec<_> =
+            solve_parallel(&days, &inputs, &Part::all(), 4.try_into().unwrap(), None)
+                .into_iter()
+                .map(answers)
+                .collect();
+
+        assert_eq!(
+            vec![
+                Some(Ok(vec![
+                    (Part::One, Ok(Answer::from(1928))),
+                    (Part::Two, Ok(Answer::from(2858)))
+                ])),
+                Some(Ok(vec![
+                    (Part::One, Ok(Answer::from(11))),
+                    (Part::Two, Ok(Answer::from(31)))
+                ])),
+                None,
+            ],
+            solved
+        );
+        assert_eq!(
+            solved[..2],
+            days[..2]
+                .iter()
+                .zip(&inputs)
+                .map(|(entry, input)| answers(Some(solve(
+                    entry,
+                    input.as_ref().unwrap(),
+                    &Part::all(),
+                    None
+                ))))
+                .collect::<Vec<_>>()
+        );
+        assert!(matches!(
+            solve_parallel(
+                &days[..1],
+                &[Ok("x".to_owned())],
+                &Part::all(),
+                4.try_into().unwrap(),
+                None
+            )[..],
+            [Some(Err(Error::Parse { .. }))]
+        ));
+    }
+
+    #[test]
+    fn inputs_with_panics() {
+        let directory = std::env::temp_dir().join("advent_of_code_inputs_with_panics");
+        let _ = std::fs::remove_dir_all(&directory);
+        let day = directory.join("year_2023/day_02");
+        std::fs::create_dir_all(&day).unwrap();
+        std::fs::write(day.join("alice.txt"), "abc").unwrap();
+        std::fs::write(day.join("bob.txt"), "abcdefg").unwrap();
+
+        let entry = Entry {
+            solver: &Panics,
+            embedded: None,
+        };
+        let mut answers = Answers::default();
+        for (input, answer) in [("abc", 3), ("abcdefg", 6)] {
+            answers.insert(
+                Key {
+                    year: 2023,
+                    day: 2,
+                    part: Part::One,
+                    input: answers::hash_input(input),
+                },
+                answer.into(),
+            );
+        }
+        let mut summary = Summary::default();
+
+        let statuses = |name: &str, answers: &mut Answers, summary: &mut Summary| {
+            input_rows(
+                &entry,
+                &day.join(name),
+                &Part::all(),
+                None,
+                answers,
+                false,
+                summary,
+            )
+            .into_iter()
+            .map(|row| row.status)
+            .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            vec![Status::Pass, Status::Unknown],
+            statuses("alice.txt", &mut answers, &mut summary)
+        );
+        assert_eq!(
+            vec![
+                Status::Fail { expected: 6.into() },
+                Status::Panicked("input is too long".to_owned())
+            ],
+            statuses("bob.txt", &mut answers, &mut summary)
+        );
+        assert_eq!(
+            Summary {
+                passed: 1,
+                failed: 2,
+                unknown: 1,
+            },
+            summary
+        );
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn silence_panics() {
+        let silenced = || SILENCED.get();
+
+        {
+            let _outer = Silenced::new();
+            assert!(silenced());
+            {
+                let _inner = Silenced::new();
+                assert!(silenced());
+            }
+            // The inner guard leaves the outer one in place, and other threads are unaffected.
+            assert!(silenced());
+            assert!(!std::thread::spawn(silenced).join().unwrap());
+
+            let panicked = panic::catch_unwind(|| panic!("silenced"));
+            assert!(panicked.is_err());
+        }
+        assert!(!silenced());
+    }
+
+    #[test]
+    fn timeouts() {
+        let entry = Entry {
+            solver: &Slow,
+            embedded: None,
+        };
+        let answers = |input| {
+            // Plenty for the parts that finish straight away, even on a busy machine.
+            solve(&entry, input, &Part::all(), Some(Duration::from_secs(1))).map(|solved| {
+                solved
+                    .parts
+                    .into_iter()
+                    .map(|part| (part.part, part.answer))
+                    .collect::<Vec<_>>()
+            })
+        };
+
+        assert_eq!(
+            Ok(vec![
+                (Part::One, Ok(Answer::from(1))),
+                (Part::Two, Ok(Answer::from(2)))
+            ]),
+            answers("")
+        );
+        // Whether or not part one stops once cancelled, part two is still solved.
+        let check = answers("check");
+        let hang = answers("hang");
+        // Parts that were solved before parsing again timed out are kept.
+        let reparse = answers("reparse");
+        // Let the abandoned part finish, such that its thread exits.
+        *RELEASE.0.lock().unwrap() = true;
+        RELEASE.1.notify_all();
+
+        for (input, answers) in [("check", check), ("hang", hang)] {
+            assert_eq!(
+                Ok(vec![
+                    (Part::One, Err(Error::TimedOut)),
+                    (Part::Two, Ok(Answer::from(2)))
+                ]),
+                answers,
+                "{input}"
+            );
+        }
+        assert_eq!(
+            Ok(vec![
+                (Part::One, Err(Error::TimedOut)),
+                (Part::Two, Err(Error::TimedOut))
+            ]),
+            reparse
+        );
+        assert_eq!(Err(Error::TimedOut), answers("parse"));
+    }
+}
@@ -0,0 +1,375 @@
+//! Extracting examples from saved puzzle pages into a day's module.
+//!
+//! A puzzle page has an `<article>` for each unlocked part. A part's example is taken to be the
+//! first `<pre><code>` block of its article (or of part one's, if part two has none), and its
+//! answer the last `<code><em>` of it, as that is how puzzles usually conclude their examples.
+//! These are guesses, so the edited module is worth a review.
+
+use std::{fmt::Display, path::Path, str::FromStr};
+
+use super::{delimited, read, write, Error};
+use crate::solution::Part;
+
+/// The attribute every example input needs, as only tests use it.
+const ATTRIBUTE: &str = r#"#[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]"#;
+
+/// An example input, and the answers to each part that it was given for.
+#[derive(Clone, Hash, Debug, PartialEq, Eq)]
+pub struct Example {
+    /// The name of its constant, e.g., `EXAMPLE_INPUT`.
+    pub name: &'static str,
+    pub input: String,
+    /// Each answer, as written on the page.
+    pub answers: Vec<(Part, String)>,
+}
+
+/// Extract the examples of every part from the saved puzzle page at `page`, then write them into
+/// the day's module at `module`, replacing any examples of the same name.
+///
+/// Returns the extracted examples.
+///
+/// # Errors
+///
+/// Returns [`Error::Io`] if a file could not be read or written, [`Error::NoExamples`] if the page
+/// has no examples with answers, or [`Error::Malformed`] if the module could not be understood.
+pub fn write_examples(module: &Path, page: &Path) -> Result<Vec<Example>, Error> {
+    let examples = extract(&read(page)?);
+    if examples.is_empty() {
+        return Err(Error::NoExamples(page.into()));
+    }
+
+    let contents =
+        insert_examples(&read(module)?, &examples).map_err(|reason| Error::Malformed {
+            path: module.into(),
+            reason,
+        })?;
+    write(module, &contents)?;
+
+    Ok(examples)
+}
+
+/// The examples of every part of a puzzle page, skipping parts without both an example and an
+/// answer.
+pub fn extract(page: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = vec![];
+    let mut first_input = None;
+
+    for (part, article) in [Part::One, Part::Two].into_iter().zip(articles(page)) {
+        let input = between(article, "<pre><code>", "</code></pre>")
+            .next()
+            .map(|block| text(block).trim_end_matches('\n').to_owned());
+        if part == Part::One {
+            first_input.clone_from(&input);
+        }
+        let Some(input) = input.or_else(|| first_input.clone()) else {
+            continue;
+        };
+        let Some(answer) = between(article, "<code><em>", "</em></code>").last() else {
+            continue;
+        };
+        let answer = (part, text(answer));
+
+        if let Some(example) = examples.iter_mut().find(|example| example.input == input) {
+            example.answers.push(answer);
+        } else {
+            examples.push(Example {
+                name: if examples.is_empty() {
+                    "EXAMPLE_INPUT"
+                } else {
+                    "EXAMPLE_INPUT_PART_TWO"
+                },
+                input,
+                answers: vec![answer],
+            });
+        }
+    }
+
+    examples
+}
+
+/// The contents of each `<article>` of `page`, in order.
+fn articles(page: &str) -> impl Iterator<Item = &str> {
+    between(page, "<article", "</article>")
+}
+
+/// The text between each `open` and the next `close` in `contents`, in order.
+fn between<'a>(contents: &'a str, open: &'a str, close: &'a str) -> impl Iterator<Item = &'a str> {
+    let mut rest = contents;
+
+    std::iter::from_fn(move || {
+        let (_, body, end) = delimited(rest, open, close)?;
+        rest = &end[close.len()..];
+        Some(body)
+    })
+}
+
+/// The text of some HTML, without its tags and with its entities unescaped.
+fn text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        rest = rest[start..]
+            .find('>')
+            .map_or("", |end| &rest[start + end + 1..]);
+    }
+    text.push_str(rest);
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Write `examples` into a day's module, replacing the constants and `examples!` entries of the
+/// same names. Answers to parts that the examples have none for are kept if the example's input is
+/// unchanged, but otherwise dropped, as they were for another input (e.g., a template's empty one).
+pub(super) fn insert_examples(module: &str, examples: &[Example]) -> Result<String, &'static str> {
+    let mut contents = module.to_owned();
+    // Where the previous example's constant ends, to keep new constants together.
+    let mut previous = None;
+    // The names of the examples whose inputs were already in the module.
+    let mut unchanged = vec![];
+
+    for example in examples {
+        let constant = constant(example);
+
+        contents = match (find_constant(&contents, example.name)?, previous) {
+            (Some((start, end)), _) => {
+                if contents[start..end] == constant {
+                    unchanged.push(example.name);
+                }
+                previous = Some(start + constant.len());
+                [&contents[..start], &constant, &contents[end..]].concat()
+            }
+            (None, Some(at)) => {
+                let item = ["\n", ATTRIBUTE, "\n", &constant].concat();
+                previous = Some(at + item.len());
+                [&contents[..at], &item, &contents[at..]].concat()
+            }
+            (None, None) => {
+                let at = after_imports(&contents);
+                let item = ["\n", ATTRIBUTE, "\n", &constant, "\n"].concat();
+                previous = Some(at + item.len() - 1);
+                [&contents[..at], &item, &contents[at..]].concat()
+            }
+        };
+    }
+
+    let Some((start, body, end)) = delimited(&contents, "examples! {", "\n}") else {
+        let entries: String = examples.iter().map(|example| entry(example, &[])).collect();
+        return Ok(format!(
+            "{}\n\ncrate::solution::examples! {{\n{entries}}}\n",
+            contents.trim_end_matches('\n'),
+        ));
+    };
+
+    let mut existing: Vec<(&str, &str)> = split_outside_strings(body, "},")
+        .into_iter()
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            entry
+                .split_once("=>")
+                .map(|(name, answers)| (name.trim(), answers.trim().trim_start_matches('{')))
+                .ok_or("expected `examples!` entries like `NAME => { part_one: ... }`")
+        })
+        .collect::<Result<_, _>>()?;
+
+    let mut entries: Vec<String> = examples
+        .iter()
+        .map(|example| {
+            let kept = existing
+                .iter()
+                .position(|(name, _)| *name == example.name)
+                .map_or_else(Vec::new, |index| answers(existing.remove(index).1));
+            if unchanged.contains(&example.name) {
+                entry(example, &kept)
+            } else {
+                entry(example, &[])
+            }
+        })
+        .collect();
+    entries.extend(
+        existing
+            .iter()
+            .map(|(name, answers)| format!("    {name} => {{ {} }},\n", answers.trim())),
+    );
+
+    Ok(format!("{start}\n{}{}", entries.concat(), &end[1..]))
+}
+
+/// The `const` item of an example, without its attribute.
+fn constant(example: &Example) -> String {
+    let literal = string_literal(&example.input);
+    let one_line = format!("const {}: &str = {literal};", example.name);
+
+    // Match `rustfmt`, which moves long literals that fit on one line onto their own.
+    if example.input.contains('\n') || one_line.len() <= 100 {
+        one_line
+    } else {
+        format!("const {}: &str =\n    {literal};", example.name)
+    }
+}
+
+/// The start and end of the `const` item named `name`, if there is one.
+fn find_constant(contents: &str, name: &str) -> Result<Option<(usize, usize)>, &'static str> {
+    const NOT_A_STRING: &str = "expected example inputs to be string literals";
+
+    let declaration = format!("const {name}: &str =");
+    let Some(start) = contents
+        .lines()
+        .scan(0, |offset, line| {
+            let start = *offset;
+            *offset += line.len() + 1;
+            Some((start, line))
+        })
+        .find_map(|(start, line)| line.starts_with(&declaration).then_some(start))
+    else {
+        return Ok(None);
+    };
+
+    let value = start + declaration.len();
+    let open = value
+        + contents[value..]
+            .find(|c: char| !c.is_whitespace())
+            .ok_or(NOT_A_STRING)?;
+    if !contents[open..].starts_with('"') {
+        return Err(NOT_A_STRING);
+    }
+
+    let mut chars = contents[open + 1..].char_indices();
+    while let Some((index, char)) = chars.next() {
+        match char {
+            '\\' => {
+                chars.next();
+            }
+            '"' if contents[open + index + 2..].starts_with(';') => {
+                return Ok(Some((start, open + index + 3)));
+            }
+            '"' => return Err(NOT_A_STRING),
+            _ => {}
+        }
+    }
+
+    Err(NOT_A_STRING)
+}
+
+/// Where to insert new items after the `use` declarations at the top of `contents`.
+fn after_imports(contents: &str) -> usize {
+    let Some(last_use) = contents
+        .rfind("\nuse ")
+        .map(|at| at + 1)
+        .or_else(|| contents.starts_with("use ").then_some(0))
+    else {
+        return 0;
+    };
+
+    contents[last_use..]
+        .find(";\n")
+        .map_or(contents.len(), |end| last_use + end + 2)
+}
+
+/// The answers of an `examples!` entry, e.g., `part_one: 11, part_two: "a,b" }`, by part.
+fn answers(entry: &str) -> Vec<(Part, &str)> {
+    let entry = entry.trim().trim_end_matches('}').trim_end();
+
+    split_outside_strings(entry, ",")
+        .into_iter()
+        .filter_map(|field| {
+            let (name, answer) = field.split_once(':')?;
+            let part = match name.trim() {
+                "part_one" => Part::One,
+                "part_two" => Part::Two,
+                _ => return None,
+            };
+
+            Some((part, answer.trim()))
+        })
+        .collect()
+}
+
+/// Split `s` on each `separator` that is not inside of a string literal.
+fn split_outside_strings<'a>(s: &'a str, separator: &str) -> Vec<&'a str> {
+    let mut pieces = vec![];
+    let mut start = 0;
+    let mut in_string = false;
+
+    let mut chars = s.char_indices();
+    while let Some((index, char)) = chars.next() {
+        match char {
+            '\\' if in_string => {
+                chars.next();
+            }
+            '"' => in_string = !in_string,
+            _ if !in_string && index >= start && s[index..].starts_with(separator) => {
+                pieces.push(&s[start..index]);
+                start = index + separator.len();
+            }
+            _ => {}
+        }
+    }
+    pieces.push(&s[start..]);
+
+    pieces
+}
+
+/// The `examples!` entry of an example, with `kept` answers to any parts the example has none for.
+fn entry(example: &Example, kept: &[(Part, &str)]) -> String {
+    let answers: Vec<String> = [Part::One, Part::Two]
+        .into_iter()
+        .filter_map(|part| {
+            let field = match part {
+                Part::One => "part_one",
+                Part::Two => "part_two",
+            };
+
+            example
+                .answers
+                .iter()
+                .find(|(p, _)| *p == part)
+                .map(|(_, answer)| format!("{field}: {}", answer_literal(answer)))
+                .or_else(|| {
+                    kept.iter()
+                        .find(|(p, _)| *p == part)
+                        .map(|(_, answer)| format!("{field}: {answer}"))
+                })
+        })
+        .collect();
+
+    format!("    {} => {{ {} }},\n", example.name, answers.join(", "))
+}
+
+/// A Rust expression for an answer, with a suffix if it does not fit the default integer type.
+///
+/// Only numbers written as they display are numbers, as with [`Answer`](crate::solution::Answer),
+/// such that, e.g., `0123` stays a string.
+fn answer_literal(answer: &str) -> String {
+    if displays_as::<i32>(answer) {
+        answer.to_owned()
+    } else if displays_as::<u64>(answer) {
+        format!("{answer}_u64")
+    } else if displays_as::<i64>(answer) {
+        format!("{answer}_i64")
+    } else if displays_as::<u128>(answer) {
+        format!("{answer}_u128")
+    } else {
+        string_literal(answer)
+    }
+}
+
+/// Whether `answer` parses as a `T` that displays as exactly `answer`.
+fn displays_as<T: FromStr + Display>(answer: &str) -> bool {
+    answer
+        .parse::<T>()
+        .is_ok_and(|parsed| parsed.to_string() == answer)
+}
+
+/// A Rust string literal containing `s`, keeping its newlines as they are.
+fn string_literal(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
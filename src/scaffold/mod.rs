@@ -0,0 +1,346 @@
+//! Scaffolding new days into the source tree.
+//!
+//! Registration edits `src/lib.rs` and `src/year_YYYY/mod.rs` by regenerating the `mod`
+//! declarations, `YEARS`, and the `days!` invocation from the names already present, so it does
+//! not depend on how they are formatted. If there is a `fuzz/` crate, a target for the new day's
+//! parser is added to it too.
+
+mod examples;
+#[cfg(test)]
+mod test;
+
+pub use examples::write_examples;
+
+use std::{
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+};
+
+use clap::ValueEnum;
+
+/// The starting point of a new day's module.
+#[derive(Clone, Copy, Hash, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Template {
+    /// An empty input and unimplemented parts.
+    #[default]
+    Blank,
+    /// An input parsed as lines of numbers separated by spaces.
+    Lines,
+    /// An input parsed as a rectangular grid of characters.
+    Grid,
+}
+
+impl Template {
+    const fn source(self) -> &'static str {
+        match self {
+            Self::Blank => include_str!("templates/blank.rs.in"),
+            Self::Lines => include_str!("templates/lines.rs.in"),
+            Self::Grid => include_str!("templates/grid.rs.in"),
+        }
+    }
+
+    /// The contents of a new day's `mod.rs`.
+    pub fn render(self, year: u16, day: u8) -> String {
+        fill(self.source(), year, day)
+    }
+}
+
+/// Fill in the `__YEAR__` and `__DAY__` placeholders of a template, which are valid identifiers so
+/// that the templates can be compiled in tests.
+fn fill(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("__YEAR__", &year.to_string())
+        .replace("__DAY__", &day.to_string())
+}
+
+/// Create and register the module for a new day in the source tree at `root`, alongside an empty
+/// `data.txt`. If this is the first day of its year, the year's module is created and registered
+/// too.
+///
+/// If `root` has a `fuzz/` crate, a target for the day's parser is added to it.
+///
+/// Returns the path to the new day's module.
+///
+/// # Errors
+///
+/// Returns [`Error::Exists`] if the day's module already exists, [`Error::Io`] if a file could not
+/// be read or written, or [`Error::Malformed`] if a module to register into could not be
+/// understood.
+#[expect(
+    clippy::missing_panics_doc,
+    reason = "a new year's module is always well-formed"
+)]
+pub fn new_day(root: &Path, year: u16, day: u8, template: Template) -> Result<PathBuf, Error> {
+    let src = root.join("src");
+    let year_directory = src.join(format!("year_{year}"));
+    let day_directory = year_directory.join(format!("day_{day:02}"));
+    let year_module = year_directory.join("mod.rs");
+
+    if day_directory.exists() {
+        return Err(Error::Exists(day_directory));
+    }
+
+    // Check every registration before writing anything, so that a failure leaves the tree as it
+    // was.
+    let fuzz_manifest = root.join("fuzz").join("Cargo.toml");
+    let fuzz_manifest_contents = if fuzz_manifest.exists() {
+        Some(register_fuzz_target(&read(&fuzz_manifest)?, year, day))
+    } else {
+        None
+    };
+    let year_module_contents = if year_module.exists() {
+        register_day(&read(&year_module)?, day).map_err(|reason| Error::Malformed {
+            path: year_module.clone(),
+            reason,
+        })?
+    } else {
+        let lib = src.join("lib.rs");
+        let lib_contents =
+            register_year(&read(&lib)?, year).map_err(|reason| Error::Malformed {
+                path: lib.clone(),
+                reason,
+            })?;
+
+        create_directory(&year_directory)?;
+        write(&lib, &lib_contents)?;
+
+        register_day(&new_year_module(year), day).expect("a new year's module is well-formed")
+    };
+
+    create_directory(&day_directory)?;
+    write(&year_module, &year_module_contents)?;
+
+    let day_module = day_directory.join("mod.rs");
+    write(&day_module, &template.render(year, day))?;
+    write(&day_directory.join("data.txt"), "")?;
+
+    if let Some(contents) = fuzz_manifest_contents {
+        write(
+            &root
+                .join("fuzz")
+                .join(format!("fuzz_targets/{}.rs", fuzz_target_name(year, day))),
+            &fuzz_target(year, day),
+        )?;
+        write(&fuzz_manifest, &contents)?;
+    }
+
+    Ok(day_module)
+}
+
+/// The name of a day's fuzz target, e.g., `year_2024_day_01`.
+fn fuzz_target_name(year: u16, day: u8) -> String {
+    format!("year_{year}_day_{day:02}")
+}
+
+/// The source of a day's fuzz target, which checks that its parser never panics.
+fn fuzz_target(year: u16, day: u8) -> String {
+    fill(include_str!("templates/fuzz_target.rs.in"), year, day)
+}
+
+/// Add a day's target to the `[[bin]]` tables of the fuzz crate's `Cargo.toml`, unless it is
+/// already there.
+fn register_fuzz_target(manifest: &str, year: u16, day: u8) -> String {
+    let name = fuzz_target_name(year, day);
+    if manifest.contains(&format!("name = \"{name}\"")) {
+        return manifest.to_owned();
+    }
+
+    format!(
+        "{}
+
+[[bin]]
+name = \"{name}\"
+path = \"fuzz_targets/{name}.rs\"
+test = false
+doc = false
+bench = false
+",
+        manifest.trim_end_matches('\n')
+    )
+}
+
+/// The contents of a new year's `mod.rs`, before any days are registered.
+fn new_year_module(year: u16) -> String {
+    format!(
+        "use crate::runner::{{days, Entry}};
+
+/// Every day of {year} that can be run.
+pub const DAYS: &[Entry] = days![
+    year_{year};
+];
+"
+    )
+}
+
+/// Add `day` to the `pub mod` declarations and the `days!` invocation of a year's module.
+fn register_day(year_module: &str, day: u8) -> Result<String, &'static str> {
+    let name = format!("day_{day:02}");
+
+    let contents = register_modules(year_module, "pub mod day_", &format!("pub mod {name};"));
+
+    let (start, body, end) =
+        delimited(&contents, "days![", "];").ok_or("expected a `days![ ... ];` invocation")?;
+
+    let mut items = body.split(';').map(str::trim).filter(|s| !s.is_empty());
+    let year = items
+        .next()
+        .ok_or("expected the year's module in `days!`")?;
+    let mut days: Vec<&str> = items.collect();
+    days.push(&name);
+    days.sort_unstable();
+    days.dedup();
+
+    let body: String = std::iter::once(year)
+        .chain(days)
+        .flat_map(|item| ["\n    ", item, ";"])
+        .chain(["\n"])
+        .collect();
+
+    Ok(format!("{start}{body}{end}"))
+}
+
+/// Add `year` to the `mod` declarations and `YEARS` of `lib.rs`.
+fn register_year(lib: &str, year: u16) -> Result<String, &'static str> {
+    const PREFIX: &str = "pub const YEARS: &[&[Entry]] = ";
+
+    let contents = register_modules(lib, "pub mod year_", &format!("pub mod year_{year};"));
+
+    let (start, body, end) =
+        delimited(&contents, &format!("{PREFIX}&["), "];").ok_or("expected a `YEARS` constant")?;
+
+    let mut years: Vec<String> = body
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(ToOwned::to_owned)
+        .collect();
+    years.push(format!("year_{year}::DAYS"));
+    years.sort_unstable();
+    years.dedup();
+
+    // Match `rustfmt`, which puts short arrays on one line.
+    let one_line = years.join(", ");
+    let body = if PREFIX.len() + one_line.len() + "&[];".len() <= 100 {
+        one_line
+    } else {
+        years
+            .iter()
+            .flat_map(|year| ["\n    ", year, ","])
+            .chain(["\n"])
+            .collect()
+    };
+
+    Ok(format!("{start}{body}{end}"))
+}
+
+/// Add `declaration` to the block of lines starting with `prefix` (e.g., `pub mod day_`), keeping
+/// them sorted. If there are no such lines, the block is created at the top of `contents`.
+fn register_modules(contents: &str, prefix: &str, declaration: &str) -> String {
+    let lines: Vec<&str> = contents.lines().collect();
+    let is_module = |line: &&str| line.starts_with(prefix) && line.ends_with(';');
+
+    let (before, mut modules, after): (&[&str], Vec<&str>, &[&str]) = match (
+        lines.iter().position(is_module),
+        lines.iter().rposition(is_module),
+    ) {
+        (Some(first), Some(last)) => (
+            &lines[..first],
+            lines[first..=last].to_vec(),
+            &lines[last + 1..],
+        ),
+        _ => (&[], vec![], &lines),
+    };
+
+    let separator = modules.is_empty() && !after.is_empty();
+    modules.push(declaration);
+    modules.sort_unstable();
+    modules.dedup();
+
+    let mut output = String::with_capacity(contents.len() + declaration.len() + 2);
+    for line in before.iter().chain(&modules) {
+        output.push_str(line);
+        output.push('\n');
+    }
+    if separator {
+        output.push('\n');
+    }
+    for line in after {
+        output.push_str(line);
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Split `contents` around the first text between `open` and the next `close`, keeping the
+/// delimiters with the surrounding text.
+fn delimited<'a>(
+    contents: &'a str,
+    open: &str,
+    close: &str,
+) -> Option<(&'a str, &'a str, &'a str)> {
+    let start = contents.find(open)? + open.len();
+    let end = start + contents[start..].find(close)?;
+
+    Some((&contents[..start], &contents[start..end], &contents[end..]))
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    std::fs::read_to_string(path).map_err(|source| Error::Io {
+        path: path.into(),
+        source,
+    })
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), Error> {
+    std::fs::write(path, contents).map_err(|source| Error::Io {
+        path: path.into(),
+        source,
+    })
+}
+
+fn create_directory(path: &Path) -> Result<(), Error> {
+    std::fs::create_dir(path).map_err(|source| Error::Io {
+        path: path.into(),
+        source,
+    })
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// When the day's module already exists.
+    Exists(PathBuf),
+    /// When a file could not be read or written.
+    Io { path: PathBuf, source: io::Error },
+    /// When a module to edit could not be understood.
+    Malformed { path: PathBuf, reason: &'static str },
+    /// When a puzzle page has no examples with answers.
+    NoExamples(PathBuf),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exists(path) => write!(f, "`{}` already exists", path.display()),
+            Self::Io { path, source } => {
+                write!(f, "failed to access `{}`: {source}", path.display())
+            }
+            Self::Malformed { path, reason } => {
+                write!(f, "failed to edit `{}`: {reason}", path.display())
+            }
+            Self::NoExamples(path) => {
+                write!(f, "found no examples with answers in `{}`", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            Self::Exists(_) | Self::Malformed { .. } | Self::NoExamples(_) => None,
+        }
+    }
+}
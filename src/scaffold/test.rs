@@ -0,0 +1,350 @@
+use std::path::Path;
+
+use super::{
+    examples::{extract, insert_examples, Example},
+    fuzz_target, new_day, new_year_module, register_day, register_fuzz_target, register_year,
+    Error, Template,
+};
+use crate::solution::Part;
+
+const YEAR_MODULE: &str = "pub mod day_01;
+pub mod day_03;
+
+use crate::runner::{days, Entry};
+
+/// Every day of 2024 that can be run.
+pub const DAYS: &[Entry] = days![
+    year_2024;
+    day_01;
+    day_03;
+];
+";
+
+const LIB: &str = "pub mod runner;
+pub mod year_2024;
+
+use runner::Entry;
+
+pub const YEARS: &[&[Entry]] = &[year_2024::DAYS];
+";
+
+#[test]
+fn register_days() {
+    assert_eq!(
+        "pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+
+use crate::runner::{days, Entry};
+
+/// Every day of 2024 that can be run.
+pub const DAYS: &[Entry] = days![
+    year_2024;
+    day_01;
+    day_02;
+    day_03;
+];
+",
+        register_day(YEAR_MODULE, 2).unwrap()
+    );
+
+    assert_eq!(
+        "pub mod day_25;
+
+use crate::runner::{days, Entry};
+
+/// Every day of 2023 that can be run.
+pub const DAYS: &[Entry] = days![
+    year_2023;
+    day_25;
+];
+",
+        register_day(&new_year_module(2023), 25).unwrap()
+    );
+
+    assert!(register_day("pub mod day_01;\n", 2).is_err());
+}
+
+#[test]
+fn register_years() {
+    assert_eq!(
+        "pub mod runner;
+pub mod year_2023;
+pub mod year_2024;
+
+use runner::Entry;
+
+pub const YEARS: &[&[Entry]] = &[year_2023::DAYS, year_2024::DAYS];
+",
+        register_year(LIB, 2023).unwrap()
+    );
+
+    assert!(register_year("pub mod year_2024;\n", 2023).is_err());
+}
+
+/// Each template, compiled as a day, so that its example tests run alongside every other test.
+mod templates {
+    const __YEAR__: u16 = 2023;
+    const __DAY__: u8 = 1;
+
+    mod blank {
+        use super::{__DAY__, __YEAR__};
+
+        include!("templates/blank.rs.in");
+    }
+
+    mod lines {
+        use super::{__DAY__, __YEAR__};
+
+        include!("templates/lines.rs.in");
+    }
+
+    mod grid {
+        use super::{__DAY__, __YEAR__};
+
+        include!("templates/grid.rs.in");
+    }
+}
+
+#[test]
+fn render() {
+    for template in [Template::Blank, Template::Lines, Template::Grid] {
+        let rendered = template.render(2024, 9);
+
+        assert!(rendered.contains("const YEAR: u16 = 2024;"));
+        assert!(rendered.contains("const DAY: u8 = 9;"));
+        assert!(!rendered.contains("__YEAR__") && !rendered.contains("__DAY__"));
+    }
+}
+
+const FUZZ_MANIFEST: &str = "[package]
+name = \"advent_of_code-fuzz\"
+
+[[bin]]
+name = \"year_2023_day_01\"
+path = \"fuzz_targets/year_2023_day_01.rs\"
+test = false
+doc = false
+bench = false
+";
+
+#[test]
+fn register_fuzz_targets() {
+    assert_eq!(FUZZ_MANIFEST, register_fuzz_target(FUZZ_MANIFEST, 2023, 1));
+    assert_eq!(
+        [
+            FUZZ_MANIFEST,
+            "
+[[bin]]
+name = \"year_2023_day_12\"
+path = \"fuzz_targets/year_2023_day_12.rs\"
+test = false
+doc = false
+bench = false
+"
+        ]
+        .concat(),
+        register_fuzz_target(FUZZ_MANIFEST, 2023, 12)
+    );
+
+    // Every registered day has a target.
+    let manifest =
+        std::fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("fuzz/Cargo.toml"))
+            .unwrap();
+    for entry in crate::YEARS.iter().flat_map(|days| days.iter()) {
+        assert_eq!(
+            manifest,
+            register_fuzz_target(&manifest, entry.year(), entry.day()),
+            "{} day {} has no fuzz target",
+            entry.year(),
+            entry.day()
+        );
+    }
+}
+
+#[test]
+fn new_days() {
+    let root = std::env::temp_dir().join("advent_of_code_new_days");
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(root.join("src")).unwrap();
+    std::fs::write(root.join("src/lib.rs"), LIB).unwrap();
+
+    let module = new_day(&root, 2023, 1, Template::Grid).unwrap();
+    assert_eq!(root.join("src/year_2023/day_01/mod.rs"), module);
+    assert_eq!(
+        Template::Grid.render(2023, 1),
+        std::fs::read_to_string(module).unwrap()
+    );
+    assert!(root.join("src/year_2023/day_01/data.txt").is_file());
+    assert_eq!(
+        register_year(LIB, 2023).unwrap(),
+        std::fs::read_to_string(root.join("src/lib.rs")).unwrap()
+    );
+    assert_eq!(
+        register_day(&new_year_module(2023), 1).unwrap(),
+        std::fs::read_to_string(root.join("src/year_2023/mod.rs")).unwrap()
+    );
+
+    assert!(matches!(
+        new_day(&root, 2023, 1, Template::Blank),
+        Err(Error::Exists(_))
+    ));
+
+    std::fs::create_dir_all(root.join("fuzz/fuzz_targets")).unwrap();
+    std::fs::write(root.join("fuzz/Cargo.toml"), FUZZ_MANIFEST).unwrap();
+    new_day(&root, 2023, 2, Template::Blank).unwrap();
+    assert_eq!(
+        fuzz_target(2023, 2),
+        std::fs::read_to_string(root.join("fuzz/fuzz_targets/year_2023_day_02.rs")).unwrap()
+    );
+    assert_eq!(
+        register_fuzz_target(FUZZ_MANIFEST, 2023, 2),
+        std::fs::read_to_string(root.join("fuzz/Cargo.toml")).unwrap()
+    );
+
+    std::fs::remove_dir_all(root).unwrap();
+}
+
+const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 3: Example ---</h2>
+<p>For example:</p>
+<pre><code>x &lt; 2
+"y" &amp; <em>z</em>
+</code></pre>
+<p>Step one gives <code><em>5</em></code>, for a total of <code><em>161</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>170807108</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>don't()
+</code></pre>
+<p>This time, the result is <code><em>48</em></code>.</p>
+</article>
+</main>"#;
+
+#[test]
+fn extract_examples() {
+    assert_eq!(
+        vec![
+            Example {
+                name: "EXAMPLE_INPUT",
+                input: "x < 2\n\"y\" & z".to_owned(),
+                answers: vec![(Part::One, "161".to_owned())],
+            },
+            Example {
+                name: "EXAMPLE_INPUT_PART_TWO",
+                input: "don't()".to_owned(),
+                answers: vec![(Part::Two, "48".to_owned())],
+            },
+        ],
+        extract(PAGE)
+    );
+
+    // Part two reuses part one's example when it has none of its own.
+    let page = PAGE.replace("<pre><code>don't()\n</code></pre>", "");
+    assert_eq!(
+        vec![Example {
+            name: "EXAMPLE_INPUT",
+            input: "x < 2\n\"y\" & z".to_owned(),
+            answers: vec![(Part::One, "161".to_owned()), (Part::Two, "48".to_owned())],
+        }],
+        extract(&page)
+    );
+
+    assert!(extract("<main><article><p>No examples.</p></article></main>").is_empty());
+}
+
+#[test]
+fn insert_examples_into_modules() {
+    let examples = [
+        Example {
+            name: "EXAMPLE_INPUT",
+            input: "1\n\"2\"".to_owned(),
+            answers: vec![(Part::One, "12345678901".to_owned())],
+        },
+        Example {
+            name: "EXAMPLE_INPUT_PART_TWO",
+            input: "3".to_owned(),
+            answers: vec![(Part::Two, "a,b".to_owned())],
+        },
+    ];
+
+    let inserted = insert_examples(&Template::Blank.render(2024, 3), &examples).unwrap();
+    assert!(inserted.contains(
+        r#"
+#[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]
+const EXAMPLE_INPUT: &str = "1
+\"2\"";
+#[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]
+const EXAMPLE_INPUT_PART_TWO: &str = "3";
+
+pub struct Day;
+"#
+    ));
+    assert!(inserted.ends_with(
+        r#"
+crate::solution::examples! {
+    EXAMPLE_INPUT => { part_one: 12345678901_u64 },
+    EXAMPLE_INPUT_PART_TWO => { part_two: "a,b" },
+}
+"#
+    ));
+
+    // Inserting again changes nothing, and other examples are kept.
+    let with_other = inserted.replace(
+        "\n}\n",
+        "\n    OTHER_EXAMPLE_INPUT => { part_one: 1 },\n}\n",
+    );
+    assert_eq!(with_other, insert_examples(&with_other, &examples).unwrap());
+
+    // Answers the page has none for are kept for the same input, but not for another.
+    let answered = with_other.replace(
+        "part_one: 12345678901_u64 }",
+        "part_one: 12345678901_u64, part_two: 5 }",
+    );
+    assert_eq!(answered, insert_examples(&answered, &examples).unwrap());
+    // E.g., the placeholders an older template gave its empty example input.
+    let placeholders = Template::Blank.render(2024, 3).replace(
+        "EXAMPLE_INPUT => {},",
+        "EXAMPLE_INPUT => { part_one: 0, part_two: 0 },",
+    );
+    assert_eq!(inserted, insert_examples(&placeholders, &examples).unwrap());
+
+    assert!(insert_examples("const EXAMPLE_INPUT: &str = 1;\n", &examples).is_err());
+}
+
+#[test]
+fn insert_awkward_answers() {
+    // Numbers not written as they display are strings, as `Answer` parses them.
+    let examples = [Example {
+        name: "EXAMPLE_INPUT",
+        input: "1".to_owned(),
+        answers: vec![(Part::One, "0123".to_owned()), (Part::Two, "+5".to_owned())],
+    }];
+    let inserted = insert_examples(&Template::Blank.render(2024, 3), &examples).unwrap();
+    assert!(inserted.ends_with(
+        r#"
+crate::solution::examples! {
+    EXAMPLE_INPUT => { part_one: "0123", part_two: "+5" },
+}
+"#
+    ));
+
+    // Entries and answers are only split outside of strings.
+    let examples = [Example {
+        name: "EXAMPLE_INPUT",
+        input: "1".to_owned(),
+        answers: vec![(Part::One, "a},b".to_owned())],
+    }];
+    let inserted = insert_examples(&Template::Blank.render(2024, 3), &examples).unwrap();
+    let answered = inserted
+        .replace(
+            r#"part_one: "a},b" }"#,
+            r#"part_one: "a},b", part_two: "}, \"part_one: 1\"" }"#,
+        )
+        .replace(
+            "\n}\n",
+            "\n    OTHER_EXAMPLE_INPUT => { part_one: \"},\" },\n}\n",
+        );
+    assert_ne!(inserted, answered);
+    assert_eq!(answered, insert_examples(&answered, &examples).unwrap());
+}
@@ -0,0 +1,104 @@
+//! Snapshot tests, comparing how values display against files of their expected output.
+//!
+//! [`assert_snapshot!`] compares a value against `snapshots/NAME.txt`, beside the file it is called
+//! from. Set [`BLESS`] to write each missing or changed snapshot instead, e.g., after an intended
+//! change of output, then review the changes to the files before committing them.
+
+use std::path::Path;
+
+/// The environment variable that, when set, writes snapshots instead of comparing against them.
+pub const BLESS: &str = "ADVENT_OF_CODE_BLESS";
+
+/// Whether [`BLESS`] is set to anything but nothing.
+pub fn blessing() -> bool {
+    std::env::var_os(BLESS).is_some_and(|value| !value.is_empty())
+}
+
+/// Compare `actual` against the snapshot at `path`, or write it there if `bless` is set and they
+/// differ.
+///
+/// # Panics
+///
+/// Panics if the snapshot is missing or differs from `actual` and `bless` is not set, or if it
+/// could not be written.
+#[track_caller]
+pub fn check(path: &Path, actual: &str, bless: bool) {
+    let contents = std::fs::read_to_string(path).ok();
+    // Snapshots end with a newline, as text files do, which `actual` need not.
+    let expected = contents
+        .as_deref()
+        .map(|contents| contents.strip_suffix('\n').unwrap_or(contents));
+
+    if expected == Some(actual) {
+        return;
+    }
+
+    if bless {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .unwrap_or_else(|err| panic!("failed to create `{}`: {err}", parent.display()));
+        }
+        std::fs::write(path, format!("{actual}\n"))
+            .unwrap_or_else(|err| panic!("failed to write `{}`: {err}", path.display()));
+        return;
+    }
+
+    match expected {
+        None => panic!(
+            "snapshot `{}` is missing, rerun with `{BLESS}=1` to write it\n\
+             --- actual\n{actual}",
+            path.display()
+        ),
+        Some(expected) => panic!(
+            "snapshot `{}` differs, rerun with `{BLESS}=1` to accept the change\n\
+             --- expected\n{expected}\n--- actual\n{actual}",
+            path.display()
+        ),
+    }
+}
+
+/// Assert that a value displays as its snapshot, `snapshots/NAME.txt` beside the calling file. E.g.,
+///
+/// ```ignore
+/// assert_snapshot!("example", Island::parse(EXAMPLE_INPUT).unwrap());
+/// ```
+macro_rules! assert_snapshot {
+    ($name:literal, $value:expr $(,)?) => {
+        $crate::snapshot::check(
+            &::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join(file!())
+                .with_file_name("snapshots")
+                .join(concat!($name, ".txt")),
+            &$value.to_string(),
+            $crate::snapshot::blessing(),
+        )
+    };
+}
+pub(crate) use assert_snapshot;
+
+#[cfg(test)]
+mod test {
+    use super::check;
+
+    #[test]
+    fn check_snapshots() {
+        let directory = std::env::temp_dir().join("advent_of_code_snapshot");
+        let _ = std::fs::remove_dir_all(&directory);
+        let path = directory.join("snapshots").join("example.txt");
+
+        let missing = std::panic::catch_unwind(|| check(&path, "1 2\n3", false));
+        assert!(missing.is_err());
+        assert!(!path.exists());
+
+        check(&path, "1 2\n3", true);
+        assert_eq!("1 2\n3\n", std::fs::read_to_string(&path).unwrap());
+        check(&path, "1 2\n3", false);
+
+        let differs = std::panic::catch_unwind(|| check(&path, "1 2\n4", false));
+        assert!(differs.is_err());
+        check(&path, "1 2\n4", true);
+        assert_eq!("1 2\n4\n", std::fs::read_to_string(&path).unwrap());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,374 @@
+use std::{
+    any::Any,
+    convert::Infallible,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use crate::{error::Result, Integer};
+
+/// A solution to both parts of a single day's puzzle.
+///
+/// The puzzle input is parsed once into [`Self::Input`], which is then shared by
+/// [`Self::part_one`] and [`Self::part_two`].
+pub trait Solution {
+    /// The parsed form of the puzzle input.
+    type Input: 'static;
+
+    /// The year of the event the puzzle belongs to, e.g., `2024`.
+    const YEAR: u16;
+    /// The day of the puzzle, from `1` to `25`.
+    const DAY: u8;
+
+    /// # Errors
+    ///
+    /// Returns an error if `input` is not a valid puzzle input.
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// # Errors
+    ///
+    /// Returns an error if the part is unimplemented, or has no answer for `input`.
+    fn part_one(input: &Self::Input) -> Result<Answer>;
+
+    /// # Errors
+    ///
+    /// Returns an error if the part is unimplemented, or has no answer for `input`.
+    fn part_two(input: &Self::Input) -> Result<Answer>;
+}
+
+/// The parsed puzzle input of some [`Solution`], with its type erased.
+pub type Parsed = Box<dyn Any>;
+
+/// An object-safe form of [`Solution`], such that solutions with different
+/// [`Solution::Input`]s can be stored and iterated over together.
+///
+/// Implemented for every [`Solution`].
+pub trait Solver {
+    fn year(&self) -> u16;
+
+    fn day(&self) -> u8;
+
+    /// # Errors
+    ///
+    /// Returns an error if `input` is not a valid puzzle input.
+    fn parse(&self, input: &str) -> Result<Parsed>;
+
+    /// # Errors
+    ///
+    /// Returns an error if the part is unimplemented, or has no answer for `input`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` was not returned by [`Self::parse`] of the same [`Solver`].
+    fn part_one(&self, input: &Parsed) -> Result<Answer>;
+
+    /// # Errors
+    ///
+    /// Returns an error if the part is unimplemented, or has no answer for `input`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` was not returned by [`Self::parse`] of the same [`Solver`].
+    fn part_two(&self, input: &Parsed) -> Result<Answer>;
+
+    /// Run either [`Self::part_one`] or [`Self::part_two`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the part is unimplemented, or has no answer for `input`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` was not returned by [`Self::parse`] of the same [`Solver`].
+    fn solve(&self, part: Part, input: &Parsed) -> Result<Answer> {
+        match part {
+            Part::One => self.part_one(input),
+            Part::Two => self.part_two(input),
+        }
+    }
+}
+
+impl<S: Solution> Solver for S {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn part_one(&self, input: &Parsed) -> Result<Answer> {
+        S::part_one(downcast::<S>(input))
+    }
+
+    fn part_two(&self, input: &Parsed) -> Result<Answer> {
+        S::part_two(downcast::<S>(input))
+    }
+}
+
+fn downcast<S: Solution>(input: &Parsed) -> &S::Input {
+    input
+        .downcast_ref()
+        .expect("`Solver::parse` of the same `Solver` produces `Solution::Input`")
+}
+
+/// The answer to one part of a puzzle.
+///
+/// Answers are equal when they [`Display`] the same, as that is all that is submitted or saved,
+/// e.g., `Answer::String("42".into()) == Answer::Integer(42)`.
+#[derive(Clone, Debug)]
+pub enum Answer {
+    /// A non-negative number, e.g., a count or a sum.
+    Integer(Integer),
+    /// A negative number.
+    Signed(i64),
+    /// A number too large for [`Integer`].
+    Big(u128),
+    /// Anything else, e.g., a comma-separated list, a code, or a password.
+    String(String),
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Self::Integer(value.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Integer::try_from(value).map_or_else(|_| Self::Big(value as u128), Self::Integer)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        Integer::try_from(value).map_or(Self::Big(value), Self::Integer)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        i64::from(value).into()
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Integer::try_from(value).map_or(Self::Signed(value), Self::Integer)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(answer) => write!(f, "{answer}"),
+            Self::Signed(answer) => write!(f, "{answer}"),
+            Self::Big(answer) => write!(f, "{answer}"),
+            Self::String(answer) => write!(f, "{answer}"),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Integer(left), Self::Integer(right)) => left == right,
+            (Self::Signed(left), Self::Signed(right)) => left == right,
+            (Self::Big(left), Self::Big(right)) => left == right,
+            (Self::String(left), Self::String(right)) => left == right,
+            _ => self.to_string() == other.to_string(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Hash for Answer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_string().hash(state);
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    /// Parses the smallest variant that fits a number written as it would be displayed (e.g., not
+    /// `0123` or `+5`), falling back to [`Self::String`], such that parsing the [`Display`] of an
+    /// answer gives back the same text.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let answer = s
+            .parse::<Integer>()
+            .map(Self::Integer)
+            .or_else(|_| s.parse().map(Self::Signed))
+            .or_else(|_| s.parse().map(Self::Big))
+            .unwrap_or_else(|_| Self::String(s.to_owned()));
+
+        Ok(if answer.to_string() == s {
+            answer
+        } else {
+            Self::String(s.to_owned())
+        })
+    }
+}
+
+/// One of the two parts of every day's puzzle.
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const fn all() -> [Self; 2] {
+        [Self::One, Self::Two]
+    }
+
+    /// `1` or `2`.
+    pub const fn number(self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::One => "One",
+                Self::Two => "Two",
+            }
+        )
+    }
+}
+
+impl FromStr for Part {
+    type Err = ParsePartError;
+
+    /// Parses `1`/`2` or `one`/`two`, case-insensitively.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "1" | "one" => Ok(Self::One),
+            "2" | "two" => Ok(Self::Two),
+            _ => Err(ParsePartError),
+        }
+    }
+}
+
+/// When a string does not name a [`Part`].
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq)]
+pub struct ParsePartError;
+
+impl Display for ParsePartError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expected `1` or `2`")
+    }
+}
+
+impl std::error::Error for ParsePartError {}
+
+/// Generate a test for every example input of a day, asserting the answers of the given parts.
+///
+/// Expects to be invoked in a day's module, alongside its `Day` and the example input constants.
+/// Parts without a known answer for an example may be omitted, and an example without any answers
+/// yet gets an ignored test. E.g.,
+///
+/// ```ignore
+/// examples! {
+///     EXAMPLE_INPUT => { part_one: 140, part_two: 80 },
+///     SIMPLE_EXAMPLE_INPUT => { part_one: 772 },
+///     LARGE_EXAMPLE_INPUT => {},
+/// }
+/// ```
+macro_rules! examples {
+    { $( $input:ident => { $( $part:ident: $answer:expr ),* $(,)? } ),+ $(,)? } => {
+        #[cfg(test)]
+        #[allow(non_snake_case, reason = "tests are named after their example input")]
+        mod examples {
+            $(
+                $crate::solution::examples!(@test $input { $( $part: $answer ),* });
+            )+
+        }
+    };
+    (@test $input:ident {}) => {
+        #[test]
+        #[ignore = "the example has no answers yet"]
+        fn $input() {
+            use $crate::solution::Solution;
+
+            <super::Day as Solution>::parse(super::$input).expect("example input should parse");
+        }
+    };
+    (@test $input:ident { $( $part:ident: $answer:expr ),+ }) => {
+        #[test]
+        fn $input() {
+            use $crate::solution::Solution;
+
+            let input = <super::Day as Solution>::parse(super::$input)
+                .expect("example input should parse");
+
+            $(
+                assert_eq!(
+                    Ok($crate::solution::Answer::from($answer)),
+                    <super::Day as Solution>::$part(&input),
+                    "{}",
+                    stringify!($part),
+                );
+            )+
+        }
+    };
+}
+pub(crate) use examples;
+
+#[cfg(test)]
+mod test {
+    use super::Answer;
+
+    #[test]
+    fn answers_round_trip() {
+        for answer in [
+            Answer::Integer(19636),
+            Answer::Signed(-4),
+            Answer::Big(5),
+            Answer::Big(u128::MAX),
+            Answer::String("19636".to_owned()),
+            Answer::String("0123".to_owned()),
+            Answer::String("a,b".to_owned()),
+        ] {
+            let reloaded: Answer = answer.to_string().parse().unwrap();
+
+            assert_eq!(answer, reloaded);
+            assert_eq!(answer.to_string(), reloaded.to_string());
+        }
+
+        assert_eq!(Answer::String("19636".to_owned()), Answer::from(19636));
+        assert_ne!(Answer::String("0123".to_owned()), Answer::from(123));
+        assert!(matches!("+5".parse(), Ok(Answer::String(_))));
+    }
+}
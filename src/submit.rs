@@ -0,0 +1,651 @@
+//! Submitting answers to the website, while remembering every verdict so that no answer is ever
+//! submitted twice, and waiting out the cooldown between submissions.
+//!
+//! Verdicts are stored in the cache directory, one per line, as `YEAR DAY PART VERDICT ANSWER`,
+//! where `VERDICT` is one of `right`, `wrong`, `too-high`, or `too-low`, e.g.,
+//! `2024 1 2 too-high 31415`. The end of the current cooldown is stored as `cooldown SECONDS`,
+//! in seconds since the Unix epoch. Blank lines and lines starting with `#` are ignored.
+
+use std::{
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    client::{self, Client},
+    solution::{Answer, Part},
+};
+
+/// The path to the verdicts file inside of a cache directory (see
+/// [`crate::input::cache_directory`]).
+pub fn path(cache: &Path) -> PathBuf {
+    cache.join("submissions.txt")
+}
+
+/// Identifies one part of one day. Unlike [`crate::answers::Key`], there is no need to identify
+/// the input, as the website only ever gives an account one input per day.
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+}
+
+/// Whether a wrong answer was too high or too low, if the website said.
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// The website's judgement of a submitted answer.
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong(Option<Hint>),
+    /// When an answer was submitted before the cooldown from a previous submission ended.
+    TooSoon,
+    /// When the part was already solved, or is not yet unlocked.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// The name of a verdict in the verdicts file, if it is recorded at all.
+    const fn name(self) -> Option<&'static str> {
+        match self {
+            Self::Right => Some("right"),
+            Self::Wrong(None) => Some("wrong"),
+            Self::Wrong(Some(Hint::TooHigh)) => Some("too-high"),
+            Self::Wrong(Some(Hint::TooLow)) => Some("too-low"),
+            Self::TooSoon | Self::WrongLevel => None,
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "right" => Some(Self::Right),
+            "wrong" => Some(Self::Wrong(None)),
+            "too-high" => Some(Self::Wrong(Some(Hint::TooHigh))),
+            "too-low" => Some(Self::Wrong(Some(Hint::TooLow))),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Right => write!(f, "right"),
+            Self::Wrong(None) => write!(f, "wrong"),
+            Self::Wrong(Some(Hint::TooHigh)) => write!(f, "wrong, too high"),
+            Self::Wrong(Some(Hint::TooLow)) => write!(f, "wrong, too low"),
+            Self::TooSoon => write!(f, "submitted too soon"),
+            Self::WrongLevel => write!(f, "already solved, or not yet unlocked"),
+        }
+    }
+}
+
+/// A [`Verdict`], and how long the website asked to wait before submitting again.
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    pub wait: Option<Duration>,
+}
+
+impl Response {
+    /// Read the verdict from the page returned by [`Client::submit`].
+    ///
+    /// Returns [`None`] if the page is not recognized.
+    pub fn parse(page: &str) -> Option<Self> {
+        let response = |verdict, wait| Some(Self { verdict, wait });
+
+        if page.contains("That's the right answer") {
+            response(Verdict::Right, None)
+        } else if page.contains("That's not the right answer") {
+            let hint = if page.contains("answer is too high") {
+                Some(Hint::TooHigh)
+            } else if page.contains("answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+
+            // E.g., "Please wait one minute before trying again."
+            let wait = after(page, "wait ")
+                .and_then(|wait| wait.split_once(" before")?.0.split_once(' '))
+                .and_then(|(amount, unit)| {
+                    let amount = match amount {
+                        "one" => 1,
+                        amount => amount.parse().ok()?,
+                    };
+
+                    duration(amount, unit)
+                })
+                .unwrap_or(Duration::from_mins(1));
+
+            response(Verdict::Wrong(hint), Some(wait))
+        } else if page.contains("You gave an answer too recently") {
+            // E.g., "You have 1m 5s left to wait."
+            let wait = after(page, "You have ")
+                .and_then(|wait| {
+                    wait.split_once(" left")?.0.split_whitespace().try_fold(
+                        Duration::ZERO,
+                        |total, part| {
+                            let unit = part.trim_start_matches(|c: char| c.is_ascii_digit());
+                            let amount = part[..part.len() - unit.len()].parse().ok()?;
+
+                            Some(total + duration(amount, unit)?)
+                        },
+                    )
+                })
+                .unwrap_or(Duration::from_mins(1));
+
+            response(Verdict::TooSoon, Some(wait))
+        } else if page.contains("You don't seem to be solving the right level") {
+            response(Verdict::WrongLevel, None)
+        } else {
+            None
+        }
+    }
+}
+
+/// The text following the first `pattern` in `s`.
+fn after<'a>(s: &'a str, pattern: &str) -> Option<&'a str> {
+    Some(&s[s.find(pattern)? + pattern.len()..])
+}
+
+/// `amount` of `unit`, which is either `h`, `m`, or `s`, or spelled out (e.g., `minutes`).
+fn duration(amount: u64, unit: &str) -> Option<Duration> {
+    let seconds = match unit.trim_end_matches('s') {
+        "h" | "hour" => 60 * 60,
+        "m" | "minute" => 60,
+        "" | "second" => 1,
+        _ => return None,
+    };
+
+    Some(Duration::from_secs(amount * seconds))
+}
+
+/// What is already known about an answer, such that it does not need to be submitted.
+#[derive(Clone, Hash, Debug, PartialEq, Eq)]
+pub enum Known {
+    /// When the part was already solved, with this answer.
+    Solved(Answer),
+    /// When this answer was already submitted, and given this verdict.
+    Submitted(Verdict),
+    /// When this answer is beyond an answer that was too high or too low.
+    Beyond(Hint, Answer),
+}
+
+impl Display for Known {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Solved(answer) => write!(f, "already solved, with {answer}"),
+            Self::Submitted(verdict) => write!(f, "already submitted, and was {verdict}"),
+            Self::Beyond(Hint::TooHigh, answer) => {
+                write!(f, "wrong, at least {answer}, which was too high")
+            }
+            Self::Beyond(Hint::TooLow, answer) => {
+                write!(f, "wrong, at most {answer}, which was too low")
+            }
+        }
+    }
+}
+
+/// What happened when trying to [`submit`] an answer.
+#[derive(Clone, Hash, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// When the answer was not submitted, because its verdict could already be known.
+    Known(Known),
+    /// When the answer was not submitted, because the cooldown has this long left.
+    Cooldown(Duration),
+    Submitted(Response),
+}
+
+/// Every verdict given to an answer, and when the cooldown from the last submission ends.
+#[derive(Clone, Hash, Debug, Default, PartialEq, Eq)]
+pub struct Submissions {
+    verdicts: Vec<(Key, Verdict, Answer)>,
+    /// The end of the cooldown, in seconds since the Unix epoch.
+    cooldown: Option<u64>,
+}
+
+impl Submissions {
+    /// Load the verdicts file at `path`, or no verdicts if it does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if `path` could not be read, or [`Error::Parse`] if it is malformed.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents, path),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(Error::Io {
+                path: path.into(),
+                source,
+            }),
+        }
+    }
+
+    /// Parse the contents of a verdicts file, reporting errors as coming from `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Parse`] if `contents` is malformed.
+    pub fn parse(contents: &str, path: &Path) -> Result<Self, Error> {
+        let mut submissions = Self::default();
+
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let error = |reason| Error::Parse {
+                path: path.into(),
+                line: index + 1,
+                reason,
+            };
+
+            if let Some(cooldown) = line.strip_prefix("cooldown ") {
+                let cooldown = cooldown
+                    .trim()
+                    .parse()
+                    .map_err(|_| error("invalid cooldown"))?;
+                submissions.cooldown = Some(cooldown);
+                continue;
+            }
+
+            // The answer is the rest of the line, as it may contain spaces.
+            let mut rest = line;
+            let [year, day, part, verdict] = [(); 4].map(|()| {
+                let (field, tail) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                rest = tail.trim_start();
+                field
+            });
+            if rest.is_empty() {
+                return Err(error("expected `YEAR DAY PART VERDICT ANSWER`"));
+            }
+
+            let key = Key {
+                year: year.parse().map_err(|_| error("invalid year"))?,
+                day: day.parse().map_err(|_| error("invalid day"))?,
+                part: part.parse().map_err(|_| error("invalid part"))?,
+            };
+            let verdict = Verdict::from_name(verdict).ok_or_else(|| error("invalid verdict"))?;
+            let Ok(answer) = rest.parse();
+
+            submissions.verdicts.push((key, verdict, answer));
+        }
+
+        Ok(submissions)
+    }
+
+    /// Write every verdict to `path`, replacing its contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if `path` could not be written to.
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let io_error = |source| Error::Io {
+            path: path.into(),
+            source,
+        };
+
+        if let Some(directory) = path.parent() {
+            std::fs::create_dir_all(directory).map_err(io_error)?;
+        }
+        std::fs::write(path, self.to_string()).map_err(io_error)
+    }
+
+    /// What is already known about submitting `answer` for `key`, if anything.
+    pub fn known(&self, key: Key, answer: &Answer) -> Option<Known> {
+        let mut verdicts = self.verdicts.iter().filter(|(k, ..)| *k == key);
+
+        if let Some((.., solved)) = verdicts
+            .clone()
+            .find(|(_, verdict, _)| *verdict == Verdict::Right)
+        {
+            return Some(Known::Solved(solved.clone()));
+        }
+
+        // Answers are equal when they display the same, so a string of digits matches the number
+        // it is reloaded as.
+        if let Some((_, verdict, _)) = verdicts.clone().find(|(.., submitted)| submitted == answer)
+        {
+            return Some(Known::Submitted(*verdict));
+        }
+
+        let number = number(answer)?;
+        verdicts.find_map(|(_, verdict, submitted)| {
+            let bound = self::number(submitted)?;
+
+            match verdict {
+                Verdict::Wrong(Some(Hint::TooHigh)) if number >= bound => {
+                    Some(Known::Beyond(Hint::TooHigh, submitted.clone()))
+                }
+                Verdict::Wrong(Some(Hint::TooLow)) if number <= bound => {
+                    Some(Known::Beyond(Hint::TooLow, submitted.clone()))
+                }
+                _ => None,
+            }
+        })
+    }
+
+    /// How long is left of the cooldown at `now`, if it has not yet ended.
+    pub fn cooldown(&self, now: SystemTime) -> Option<Duration> {
+        let end = SystemTime::UNIX_EPOCH + Duration::from_secs(self.cooldown?);
+
+        end.duration_since(now).ok().filter(|left| !left.is_zero())
+    }
+
+    /// Record the `response` to submitting `answer` for `key` at `now`.
+    pub fn record(&mut self, key: Key, answer: Answer, response: Response, now: SystemTime) {
+        if response.verdict.name().is_some() {
+            self.verdicts.push((key, response.verdict, answer));
+        }
+
+        if let Some(wait) = response.wait {
+            let end = now + wait;
+            // Round up, so the cooldown is never cut short.
+            self.cooldown = end
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .ok()
+                .map(|end| end.as_secs() + u64::from(end.subsec_nanos() > 0));
+        }
+    }
+}
+
+impl Display for Submissions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# YEAR DAY PART VERDICT ANSWER")?;
+
+        for (key, verdict, answer) in &self.verdicts {
+            let verdict = verdict.name().expect("only named verdicts are recorded");
+            writeln!(
+                f,
+                "{} {} {} {verdict} {answer}",
+                key.year,
+                key.day,
+                key.part.number()
+            )?;
+        }
+
+        if let Some(cooldown) = self.cooldown {
+            writeln!(f, "cooldown {cooldown}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An answer as a number, if it is one.
+fn number(answer: &Answer) -> Option<i128> {
+    match answer {
+        Answer::Integer(answer) => Some((*answer).into()),
+        Answer::Signed(answer) => Some((*answer).into()),
+        Answer::Big(answer) => (*answer).try_into().ok(),
+        Answer::String(_) => None,
+    }
+}
+
+/// Submit `answer` for `key` at `now`, unless its verdict can already be known from
+/// `submissions`, or the cooldown has not yet ended. The response is recorded into `submissions`.
+///
+/// # Errors
+///
+/// Returns [`Error::Client`] if the answer could not be submitted, or [`Error::Unrecognized`] if
+/// the website's response could not be understood.
+pub fn submit(
+    client: &Client,
+    submissions: &mut Submissions,
+    key: Key,
+    answer: Answer,
+    now: SystemTime,
+) -> Result<Outcome, Error> {
+    if let Some(known) = submissions.known(key, &answer) {
+        return Ok(Outcome::Known(known));
+    }
+
+    if let Some(left) = submissions.cooldown(now) {
+        return Ok(Outcome::Cooldown(left));
+    }
+
+    let page = client
+        .submit(key.year, key.day, key.part, &answer)
+        .map_err(Error::Client)?;
+    let response = Response::parse(&page).ok_or(Error::Unrecognized)?;
+    submissions.record(key, answer, response, now);
+
+    Ok(Outcome::Submitted(response))
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// When the verdicts file could not be read or written.
+    Io { path: PathBuf, source: io::Error },
+    /// When a line of the verdicts file is malformed.
+    Parse {
+        path: PathBuf,
+        line: usize,
+        reason: &'static str,
+    },
+    /// When an answer could not be submitted.
+    Client(client::Error),
+    /// When the website's response to a submission could not be understood.
+    Unrecognized,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { path, source } => {
+                write!(
+                    f,
+                    "failed to access verdicts `{}`: {source}",
+                    path.display()
+                )
+            }
+            Self::Parse { path, line, reason } => {
+                write!(f, "{}:{line}: {reason}", path.display())
+            }
+            Self::Client(err) => write!(f, "failed to submit: {err}"),
+            Self::Unrecognized => write!(f, "the website's response was not recognized"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            Self::Client(err) => Some(err),
+            Self::Parse { .. } | Self::Unrecognized => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        path::Path,
+        time::{Duration, SystemTime},
+    };
+
+    use super::{submit, Hint, Key, Known, Outcome, Response, Submissions, Verdict};
+    use crate::{
+        client::{stand_in, Client},
+        solution::{Answer, Part},
+    };
+
+    const KEY: Key = Key {
+        year: 2024,
+        day: 1,
+        part: Part::One,
+    };
+
+    #[test]
+    fn responses() {
+        let response = |verdict, wait: Option<u64>| {
+            Some(Response {
+                verdict,
+                wait: wait.map(Duration::from_secs),
+            })
+        };
+
+        assert_eq!(
+            response(Verdict::Right, None),
+            Response::parse("<p>That's the right answer!  You are one gold star closer.</p>")
+        );
+        assert_eq!(
+            response(Verdict::Wrong(Some(Hint::TooHigh)), Some(60)),
+            Response::parse(
+                "<p>That's not the right answer; your answer is too high.  \
+                 Please wait one minute before trying again.</p>"
+            )
+        );
+        assert_eq!(
+            response(Verdict::Wrong(None), Some(300)),
+            Response::parse(
+                "<p>That's not the right answer.  Please wait 5 minutes before trying again.</p>"
+            )
+        );
+        assert_eq!(
+            response(Verdict::TooSoon, Some(65)),
+            Response::parse(
+                "<p>You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 5s left to wait.</p>"
+            )
+        );
+        assert_eq!(
+            response(Verdict::WrongLevel, None),
+            Response::parse(
+                "<p>You don't seem to be solving the right level.  Did you already complete it?</p>"
+            )
+        );
+        assert_eq!(None, Response::parse("<p>Something else entirely.</p>"));
+    }
+
+    #[test]
+    fn known() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let wrong = |hint| Response {
+            verdict: Verdict::Wrong(hint),
+            wait: Some(Duration::from_mins(1)),
+        };
+
+        let mut submissions = Submissions::default();
+        submissions.record(KEY, 50.into(), wrong(Some(Hint::TooHigh)), now);
+        submissions.record(KEY, 10.into(), wrong(Some(Hint::TooLow)), now);
+        submissions.record(KEY, "x".into(), wrong(None), now);
+
+        assert_eq!(
+            Some(Known::Beyond(Hint::TooHigh, 50.into())),
+            submissions.known(KEY, &51.into())
+        );
+        assert_eq!(
+            Some(Known::Beyond(Hint::TooLow, 10.into())),
+            submissions.known(KEY, &(-3).into())
+        );
+        assert_eq!(
+            Some(Known::Submitted(Verdict::Wrong(None))),
+            submissions.known(KEY, &"x".into())
+        );
+        assert_eq!(None, submissions.known(KEY, &20.into()));
+        assert_eq!(
+            None,
+            submissions.known(
+                Key {
+                    part: Part::Two,
+                    ..KEY
+                },
+                &51.into()
+            )
+        );
+
+        assert_eq!(
+            Some(Duration::from_secs(30)),
+            submissions.cooldown(now + Duration::from_secs(30))
+        );
+        assert_eq!(None, submissions.cooldown(now + Duration::from_mins(1)));
+
+        let right = Response {
+            verdict: Verdict::Right,
+            wait: None,
+        };
+        submissions.record(KEY, 20.into(), right, now);
+        assert_eq!(
+            Some(Known::Solved(20.into())),
+            submissions.known(KEY, &21.into())
+        );
+
+        let path = Path::new("submissions.txt");
+        assert_eq!(
+            submissions,
+            Submissions::parse(&submissions.to_string(), path).unwrap()
+        );
+    }
+
+    #[test]
+    fn known_after_reload() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+        let answer = Answer::String("19636".to_owned());
+
+        let mut submissions = Submissions::default();
+        submissions.record(
+            KEY,
+            answer.clone(),
+            Response {
+                verdict: Verdict::Wrong(None),
+                wait: None,
+            },
+            now,
+        );
+        let reloaded =
+            Submissions::parse(&submissions.to_string(), Path::new("submissions.txt")).unwrap();
+
+        for submissions in [submissions, reloaded] {
+            assert_eq!(
+                Some(Known::Submitted(Verdict::Wrong(None))),
+                submissions.known(KEY, &answer)
+            );
+            assert_eq!(
+                Some(Known::Submitted(Verdict::Wrong(None))),
+                submissions.known(KEY, &19636.into())
+            );
+        }
+    }
+
+    #[test]
+    fn submit_once() {
+        let (base_url, server) = stand_in::serve(vec![(
+            200,
+            "<p>That's not the right answer.  Please wait one minute before trying again.</p>",
+        )]);
+        let client = Client::new(&base_url, "token");
+        let now = SystemTime::now();
+        let answer = Answer::from(42);
+
+        let mut submissions = Submissions::default();
+        assert_eq!(
+            Outcome::Submitted(Response {
+                verdict: Verdict::Wrong(None),
+                wait: Some(Duration::from_mins(1)),
+            }),
+            submit(&client, &mut submissions, KEY, answer.clone(), now).unwrap()
+        );
+        // The stand-in only serves one request, so neither of these may be submitted.
+        assert_eq!(
+            Outcome::Known(Known::Submitted(Verdict::Wrong(None))),
+            submit(&client, &mut submissions, KEY, answer, now).unwrap()
+        );
+        assert!(matches!(
+            submit(&client, &mut submissions, KEY, 43.into(), now).unwrap(),
+            Outcome::Cooldown(_)
+        ));
+
+        assert!(server.join().unwrap()[0].ends_with("level=1&answer=42"));
+    }
+}
@@ -0,0 +1,383 @@
+//! Re-running a day whenever its module or input changes.
+//!
+//! Solutions are compiled in, so each run goes through `cargo`: first the day's example tests,
+//! then `run --format csv`, whose answers are compared against those of the previous run.
+
+use std::{
+    collections::BTreeMap,
+    convert::Infallible,
+    ffi::OsStr,
+    io,
+    path::{Path, PathBuf},
+    process::{Command, Output},
+    time::{Duration, SystemTime},
+};
+
+use crate::{input, solution::Part};
+
+/// A day to watch, and how to run it.
+#[derive(Clone, Hash, Debug, PartialEq, Eq)]
+pub struct Watch {
+    pub year: u16,
+    pub day: u8,
+    /// Read the input from this file, instead of searching for it. `cargo` runs in the package's
+    /// directory, so this should be absolute, e.g., [canonicalized](std::fs::canonicalize).
+    pub input: Option<PathBuf>,
+    /// Build with optimizations.
+    pub release: bool,
+}
+
+impl Watch {
+    /// The day's module directory, and every path its input may be read from.
+    pub fn paths(&self) -> Vec<PathBuf> {
+        let module = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src")
+            .join(format!("year_{}", self.year))
+            .join(format!("day_{:02}", self.day));
+
+        std::iter::once(module)
+            .chain(self.input.clone().map_or_else(
+                || input::search_paths(self.year, self.day),
+                |path| vec![path],
+            ))
+            .collect()
+    }
+
+    /// Run the day, then again each time any of its [`paths`](Self::paths) change, checking every
+    /// `interval`.
+    ///
+    /// # Errors
+    ///
+    /// Only returns, with an error, if `cargo` could not be run.
+    pub fn run(&self, interval: Duration) -> io::Result<Infallible> {
+        let paths = self.paths();
+        let mut previous = None;
+
+        loop {
+            let snapshot = Snapshot::take(&paths);
+            println!("Running {} day {}", self.year, self.day);
+
+            let answers = match self.examples()? {
+                Ok(examples) => {
+                    println!("  - Examples: {examples}");
+                    self.answers()?
+                }
+                Err(err) => Err(err),
+            };
+
+            match answers {
+                Ok(answers) => {
+                    print!("{}", answers.diff(previous.as_ref()));
+                    previous = Some(answers);
+                }
+                Err(err) => println!("  - Error: {err}"),
+            }
+
+            let mut latest = Snapshot::after(interval, &paths);
+            while latest == snapshot {
+                latest = Snapshot::after(interval, &paths);
+            }
+            // Wait for the changes to settle, as editors may write more than one file at a time.
+            loop {
+                let next = Snapshot::after(interval, &paths);
+                if next == latest {
+                    break;
+                }
+                latest = next;
+            }
+            println!();
+        }
+    }
+
+    /// Run the day's example tests, summarizing their results, or returning the build's errors.
+    fn examples(&self) -> io::Result<Result<String, String>> {
+        let filter = format!("year_{}::day_{:02}::examples::", self.year, self.day);
+        let output = self.cargo("test", ["--", &filter])?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        // E.g., `test result: ok. 1 passed; 0 failed; 0 ignored; ...`, for each test binary.
+        let results: Vec<String> = stdout
+            .lines()
+            .filter_map(|line| line.strip_prefix("test result: ")?.split_once(". "))
+            .map(|(_, counts)| counts.split("; ").take(2).collect::<Vec<_>>().join(", "))
+            .collect();
+        let summary = results
+            .iter()
+            .find(|counts| *counts != "0 passed, 0 failed")
+            .or_else(|| results.first())
+            .cloned();
+
+        Ok(match (output.status.success(), summary) {
+            (true, Some(summary)) => Ok(summary),
+            (false, Some(summary)) => Ok(format!(
+                "{summary}\n{}",
+                failures(&stdout).unwrap_or_default().trim()
+            )),
+            (_, None) => Err(format!(
+                "failed to build\n{}",
+                String::from_utf8_lossy(&output.stderr).trim_end()
+            )),
+        })
+    }
+
+    /// Run the day, reading its answers from the output.
+    fn answers(&self) -> io::Result<Result<Answers, String>> {
+        let year = self.year.to_string();
+        let day = self.day.to_string();
+        let mut args: Vec<&OsStr> = [
+            "--", "run", "--year", &year, "--day", &day, "--format", "csv",
+        ]
+        .map(OsStr::new)
+        .into();
+        if let Some(input) = &self.input {
+            args.extend([OsStr::new("--input"), input.as_os_str()]);
+        }
+
+        let output = self.cargo("run", &args)?;
+        if !output.status.success() {
+            return Ok(Err(format!(
+                "failed to run\n{}",
+                String::from_utf8_lossy(&output.stderr).trim_end()
+            )));
+        }
+
+        Ok(Answers::parse(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    /// Run a quiet `cargo` command in this package, e.g., `test`, capturing its output.
+    fn cargo<S: AsRef<OsStr>>(
+        &self,
+        command: &str,
+        args: impl IntoIterator<Item = S>,
+    ) -> io::Result<Output> {
+        Command::new(env!("CARGO"))
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .args([command, "--quiet"])
+            .args(self.release.then_some("--release"))
+            .args(args)
+            // Keep failing examples compact.
+            .env("RUST_BACKTRACE", "0")
+            .output()
+    }
+}
+
+/// The failures section of `cargo test` output, i.e., each failing test's output.
+fn failures(stdout: &str) -> Option<&str> {
+    let (_, failures) = stdout.split_once("failures:\n")?;
+    Some(failures.split("\nfailures:\n").next().unwrap_or(failures))
+}
+
+/// The modification time and length of every file under some paths, which changes when any of
+/// them are written, created, or removed.
+#[derive(Clone, Hash, Debug, PartialEq, Eq)]
+pub struct Snapshot(Vec<(PathBuf, Option<(SystemTime, u64)>)>);
+
+impl Snapshot {
+    pub fn take(paths: &[PathBuf]) -> Self {
+        let mut files = vec![];
+        for path in paths {
+            visit(path, &mut files);
+        }
+
+        Self(files)
+    }
+
+    fn after(interval: Duration, paths: &[PathBuf]) -> Self {
+        std::thread::sleep(interval);
+        Self::take(paths)
+    }
+}
+
+fn visit(path: &Path, files: &mut Vec<(PathBuf, Option<(SystemTime, u64)>)>) {
+    let Ok(metadata) = std::fs::metadata(path) else {
+        files.push((path.to_owned(), None));
+        return;
+    };
+
+    if metadata.is_dir() {
+        let mut entries: Vec<PathBuf> = std::fs::read_dir(path)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .collect();
+        entries.sort_unstable();
+
+        for entry in entries {
+            visit(&entry, files);
+        }
+    } else {
+        let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+        files.push((path.to_owned(), Some((modified, metadata.len()))));
+    }
+}
+
+/// The results of one run of a day.
+#[derive(Clone, Hash, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    parse: Option<Duration>,
+    /// Each part's answer (or error), and how long it took to solve.
+    parts: BTreeMap<Part, (String, Option<Duration>)>,
+}
+
+impl Answers {
+    /// Parse the output of `run --format csv` for a single day.
+    fn parse(csv: &str) -> Result<Self, String> {
+        let mut answers = Self::default();
+
+        for record in records(csv).iter().skip(1) {
+            let [_, _, part, answer, _, status, parse, solve, error, ..] = record.as_slice() else {
+                return Err(format!("unexpected output: {record:?}"));
+            };
+            let Ok(part) = part.parse() else {
+                return Err(format!("unexpected part: {part}"));
+            };
+            let nanos = |nanos: &String| nanos.parse().ok().map(Duration::from_nanos);
+            let answer = if status == "error" {
+                format!("Error: {error}")
+            } else {
+                answer.clone()
+            };
+
+            answers.parse = nanos(parse);
+            answers.parts.insert(part, (answer, nanos(solve)));
+        }
+
+        Ok(answers)
+    }
+
+    /// A line per part, comparing each answer to that of the `previous` run.
+    fn diff(&self, previous: Option<&Self>) -> String {
+        let parse = self
+            .parse
+            .map(|parse| format!("  - Parse: {parse:.2?}\n"))
+            .unwrap_or_default();
+
+        let parts = self.parts.iter().map(|(part, (answer, solve))| {
+            let change = match previous.and_then(|previous| previous.parts.get(part)) {
+                None => None,
+                Some((previous, _)) if previous == answer => Some("unchanged".to_owned()),
+                Some((previous, _)) => Some(format!("was {previous}")),
+            };
+            let details: Vec<String> = change
+                .into_iter()
+                .chain(solve.map(|solve| format!("{solve:.2?}")))
+                .collect();
+
+            if details.is_empty() {
+                format!("  - Part {part}: {answer}\n")
+            } else {
+                format!("  - Part {part}: {answer} ({})\n", details.join(", "))
+            }
+        });
+
+        std::iter::once(parse).chain(parts).collect()
+    }
+}
+
+/// The records of some CSV, allowing quoted fields to contain commas, quotes, and newlines.
+fn records(csv: &str) -> Vec<Vec<String>> {
+    let mut records = vec![];
+    let mut record = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = csv.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match (char, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', _) => quoted = !quoted,
+            (',', false) => record.push(std::mem::take(&mut field)),
+            ('\n', false) => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            (char, _) => field.push(char),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    records
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{records, Answers, Snapshot};
+    use crate::solution::Part;
+
+    #[test]
+    fn parse_records() {
+        assert_eq!(
+            vec![vec!["a", "b,c", ""], vec!["\"d\"\ne", "f"],],
+            records("a,\"b,c\",\n\"\"\"d\"\"\ne\",f\n")
+        );
+    }
+
+    #[test]
+    fn diff_answers() {
+        let first = Answers::parse(
+            "year,day,part,answer,expected,status,parse_ns,solve_ns,error,allocations,allocated_bytes,peak_bytes
+2024,1,1,11,,solved,5000,518,,,,
+2024,1,2,,,error,5000,518,\"not yet implemented, \"\"soon\"\"\",,,
+",
+        )
+        .unwrap();
+        assert_eq!(Some(Duration::from_micros(5)), first.parse);
+        assert_eq!(
+            Some(&(
+                "Error: not yet implemented, \"soon\"".to_owned(),
+                Some(Duration::from_nanos(518))
+            )),
+            first.parts.get(&Part::Two)
+        );
+
+        assert_eq!(
+            "  - Parse: 5.00µs
+  - Part One: 11 (518.00ns)
+  - Part Two: Error: not yet implemented, \"soon\" (518.00ns)
+",
+            first.diff(None)
+        );
+
+        let second = Answers::parse(
+            "year,day,part,answer,expected,status,parse_ns,solve_ns,error,allocations,allocated_bytes,peak_bytes
+2024,1,1,11,,solved,5000,518,,,,
+2024,1,2,31,,solved,5000,1000,,,,
+",
+        )
+        .unwrap();
+        assert_eq!(
+            "  - Parse: 5.00µs
+  - Part One: 11 (unchanged, 518.00ns)
+  - Part Two: 31 (was Error: not yet implemented, \"soon\", 1.00µs)
+",
+            second.diff(Some(&first))
+        );
+    }
+
+    #[test]
+    fn snapshots() {
+        let directory = std::env::temp_dir().join("advent_of_code_snapshots");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+        let paths = [directory.clone(), directory.join("../missing.txt")];
+
+        let empty = Snapshot::take(&paths);
+        std::fs::write(directory.join("mod.rs"), "").unwrap();
+        let created = Snapshot::take(&paths);
+        assert_ne!(empty, created);
+        assert_eq!(created, Snapshot::take(&paths));
+
+        std::fs::write(directory.join("mod.rs"), "pub struct Day;").unwrap();
+        assert_ne!(created, Snapshot::take(&paths));
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
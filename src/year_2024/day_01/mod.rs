@@ -1,64 +1,89 @@
-// TODO: Make days into traits.
-//
-// TODO: Proper error handling!
+use crate::{
+    error::{self, Error, Result},
+    solution::{Answer, Solution},
+    Integer,
+};
 
-const INPUT: &str = include_str!("./data.txt");
+#[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]
+const EXAMPLE_INPUT: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
 
-pub fn part_one() -> u32 {
-    let (mut left, mut right): (Vec<u32>, Vec<u32>) = unzip(INPUT);
+pub struct Day;
 
-    // Sort the two vectors.
-    left.sort_unstable();
-    right.sort_unstable();
+impl Solution for Day {
+    type Input = (Vec<u32>, Vec<u32>);
 
-    // Pair either side back up, get the difference of each pair, then get the sum of those
-    // differences.
-    left.into_iter()
-        .zip(right)
-        .map(|(left, right)| left.max(right) - left.min(right))
-        .sum()
-}
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
 
-pub fn part_two() -> u32 {
-    let (mut left, mut right) = unzip(INPUT);
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (mut left, mut right) = unzip(input)?;
 
-    // Sort the two vectors.
-    left.sort_unstable();
-    right.sort_unstable();
+        // Sort the two vectors.
+        left.sort_unstable();
+        right.sort_unstable();
 
-    left.iter()
-        .map(|location_id| {
-            // Returns the number of times [`location_id`] appears in [`right`] times
-            // [`location_id`].
-            location_id
-                * right.iter().fold(0, |count, next| {
-                    if next == location_id {
-                        count + 1
-                    } else {
-                        count
-                    }
-                })
-        })
-        .sum()
+        Ok((left, right))
+    }
+
+    fn part_one((left, right): &Self::Input) -> Result<Answer> {
+        // Pair either side back up, get the difference of each pair, then get the sum of those
+        // differences.
+        Ok(left
+            .iter()
+            .zip(right)
+            .map(|(&left, &right)| Integer::from(left.abs_diff(right)))
+            .sum::<Integer>()
+            .into())
+    }
+
+    fn part_two((left, right): &Self::Input) -> Result<Answer> {
+        Ok(left
+            .iter()
+            .map(|&location_id| {
+                // Returns the number of times [`location_id`] appears in [`right`] times
+                // [`location_id`].
+                Integer::from(location_id)
+                    * right.iter().fold(0, |count, &next| {
+                        if next == location_id {
+                            count + 1
+                        } else {
+                            count
+                        }
+                    })
+            })
+            .sum::<Integer>()
+            .into())
+    }
 }
 
 /// Convert `/\d+ +\d+/` (number spaces number) two vectors of [`u32`] for the two sides.
-fn unzip(input: &str) -> (Vec<u32>, Vec<u32>) {
+fn unzip(input: &str) -> Result<(Vec<u32>, Vec<u32>)> {
+    const EXPECTED: &str = "expected a number, spaces, and then one more number";
+
     input
         .lines()
-        .map(|line| {
-            let sliced = line
-                .split(' ')
-                .filter(|str| !str.is_empty())
-                .map(|str| {
+        .enumerate()
+        .map(|(line_index, line)| {
+            let mut numbers = error::split_columns(line, ' ')
+                .filter(|(_, str)| !str.is_empty())
+                .map(|(column, str)| {
                     str.parse::<u32>()
-                        .expect("a number, spaces, and then one more number")
-                })
-                .collect::<Vec<u32>>();
-            let [left, right] = sliced.as_slice() else {
-                panic!("Malformed input; expected a number, spaces, and then one more number")
-            };
-            (*left, *right)
+                        .map_err(|_| Error::parse(line_index, column, EXPECTED))
+                });
+
+            match (numbers.next(), numbers.next(), numbers.next()) {
+                (Some(left), Some(right), None) => Ok((left?, right?)),
+                _ => Err(Error::parse(line_index, 0, EXPECTED)),
+            }
         })
-        .unzip()
+        .collect()
+}
+
+crate::solution::examples! {
+    EXAMPLE_INPUT => { part_one: 11, part_two: 31 },
 }
@@ -1,6 +1,7 @@
-// TODO: Make days into traits.
-//
-// TODO: Proper error handling!
+use crate::{
+    error::{self, Error, Result},
+    solution::{Answer, Solution},
+};
 
 macro_rules! safe_or_break {
     ($safe:ident, $($fn:expr),+) => {
@@ -14,8 +15,8 @@ macro_rules! safe_or_break {
     };
 }
 
-const INPUT: &str = include_str!("./data.txt");
-const _INPUT: &str = "7 6 4 2 1
+#[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]
+const EXAMPLE_INPUT: &str = "7 6 4 2 1
 1 2 7 8 9
 9 7 6 2 1
 1 3 2 4 5
@@ -30,7 +31,7 @@ enum Report {
 }
 
 impl Report {
-    pub fn is_safe(&self) -> bool {
+    pub const fn is_safe(self) -> bool {
         matches!(self, Self::Safe)
     }
 }
@@ -42,26 +43,45 @@ enum Direction {
 }
 
 impl Direction {
-    pub fn from_int(lhs: u32, rhs: u32) -> Self {
+    pub const fn from_int(lhs: u32, rhs: u32) -> Self {
         if lhs < rhs {
-            Direction::Increasing
+            Self::Increasing
         } else {
-            Direction::Decreasing
+            Self::Decreasing
         }
     }
 }
 
-pub fn part_one() -> u32 {
-    let reports = parse(INPUT);
+pub struct Day;
 
+impl Solution for Day {
+    type Input = Vec<Vec<u32>>;
+
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(reports: &Self::Input) -> Result<Answer> {
+        Ok(part_one(reports))
+    }
+
+    fn part_two(reports: &Self::Input) -> Result<Answer> {
+        Ok(part_two(reports))
+    }
+}
+
+fn part_one(reports: &[Vec<u32>]) -> Answer {
     reports
-        .into_iter()
+        .iter()
         .filter(|report| {
             let mut previous = None;
             let mut direction = None;
             let mut safe = Report::Safe;
 
-            for level in report.iter() {
+            for level in *report {
                 let Some(last) = previous else {
                     previous = Some(level);
                     continue;
@@ -82,12 +102,11 @@ pub fn part_one() -> u32 {
             }
         })
         .count()
-        .try_into()
-        .unwrap()
+        .into()
 }
 
 // This works, but could definitely do with some optimization.
-pub fn part_two() -> u32 {
+fn part_two(reports: &[Vec<u32>]) -> Answer {
     fn evaluate_direction(report: &[u32]) -> Option<Direction> {
         // Could probably find offending indicies using `.find()`
         if report.is_sorted() {
@@ -101,7 +120,7 @@ pub fn part_two() -> u32 {
 
     fn evaluate_stepping(report: &[u32]) -> Report {
         let mut prev = None;
-        for value in report.iter() {
+        for value in report {
             let Some(last) = prev else {
                 prev = Some(value);
                 continue;
@@ -138,10 +157,8 @@ pub fn part_two() -> u32 {
         Report::Unsafe
     }
 
-    let reports = parse(INPUT);
-
     reports
-        .into_iter()
+        .iter()
         .filter(|report| {
             let mut safe = evaluate(report);
             if !safe.is_safe() {
@@ -150,18 +167,17 @@ pub fn part_two() -> u32 {
             safe.is_safe()
         })
         .count()
-        .try_into()
-        .unwrap()
+        .into()
 }
 
 /// Adjacent levels must differ by at least one and at most three.
 fn verify_difference(previous_level: u32, level: u32) -> Report {
     let difference = previous_level.max(level) - previous_level.min(level);
 
-    if !ACCEPTABLE_DIFFERENCE.contains(&difference) {
-        Report::Unsafe
-    } else {
+    if ACCEPTABLE_DIFFERENCE.contains(&difference) {
         Report::Safe
+    } else {
+        Report::Unsafe
     }
 }
 
@@ -190,13 +206,22 @@ fn verify_or_set_direction(
 }
 
 /// Convert `/(\d+ )+ \d+/` (numbers separated by spaces) to a two-dimensional vector.
-fn parse(input: &str) -> Vec<Vec<u32>> {
+fn parse(input: &str) -> Result<Vec<Vec<u32>>> {
     input
         .lines()
-        .map(|line| {
-            line.split(' ')
-                .map(|str| str.parse::<u32>().expect("numbers separated by spaces"))
+        .enumerate()
+        .map(|(line_index, line)| {
+            error::split_columns(line, ' ')
+                .map(|(column, str)| {
+                    str.parse::<u32>().map_err(|_| {
+                        Error::parse(line_index, column, "expected numbers separated by spaces")
+                    })
+                })
                 .collect()
         })
         .collect()
 }
+
+crate::solution::examples! {
+    EXAMPLE_INPUT => { part_one: 2, part_two: 4 },
+}
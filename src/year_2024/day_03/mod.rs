@@ -1,23 +1,54 @@
 mod stack;
 use stack::{DoStack, DontStack, MulStack, Stack};
 
-const INPUT: &str = include_str!("./data.txt");
-const _INPUT: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
-const __INPUT: &str = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+use crate::{
+    error::{Error, Result},
+    solution::{Answer, Solution},
+};
 
-pub fn part_one() -> u32 {
+#[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]
+const EXAMPLE_INPUT: &str =
+    "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+#[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]
+const EXAMPLE_INPUT_PART_TWO: &str =
+    "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Box<str>;
+
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.into())
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer> {
+        part_one(input).map(Into::into)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer> {
+        part_two(input).map(Into::into)
+    }
+}
+
+fn part_one(input: &str) -> Result<u32> {
     let mut stack = MulStack::new();
     let mut total: u32 = 0;
 
-    for char in INPUT.chars() {
-        total += stack.eval_if_valid();
+    for char in input.chars() {
+        total = total
+            .checked_add(stack.eval_if_valid()?)
+            .ok_or(Error::Overflow)?;
         stack.push_or_clear(char);
     }
 
-    total
+    Ok(total)
 }
 
-pub fn part_two() -> u32 {
+fn part_two(input: &str) -> Result<u32> {
     let mut mul_stack = MulStack::new();
     let mut total: u32 = 0;
 
@@ -25,7 +56,7 @@ pub fn part_two() -> u32 {
     let mut dont_stack = DontStack::new();
     let mut do_mode = true;
 
-    for char in INPUT.chars() {
+    for char in input.chars() {
         if do_stack.clear_if_valid() {
             do_mode = true;
         }
@@ -33,7 +64,9 @@ pub fn part_two() -> u32 {
             do_mode = false;
         }
 
-        total += mul_stack.eval_if_valid();
+        total = total
+            .checked_add(mul_stack.eval_if_valid()?)
+            .ok_or(Error::Overflow)?;
 
         if !do_mode {
             do_stack.push_or_clear(char);
@@ -43,5 +76,10 @@ pub fn part_two() -> u32 {
         dont_stack.push_or_clear(char);
     }
 
-    total
+    Ok(total)
+}
+
+crate::solution::examples! {
+    EXAMPLE_INPUT => { part_one: 161 },
+    EXAMPLE_INPUT_PART_TWO => { part_two: 48 },
 }
@@ -1,3 +1,5 @@
+use crate::error::{Error, Result};
+
 pub trait Stack<P: PartialEq<char>>
 where
     Self: std::fmt::Debug,
@@ -55,38 +57,41 @@ impl MulStack {
         self.as_str()
             .chars()
             .rev()
-            .take_while(|c| c.is_numeric())
+            .take_while(char::is_ascii_digit)
             .count()
     }
 
     /// If the stack is valid, drain the stack and evaluate the result. Otherwise, do nothing and
     /// return `0`.
-    pub fn eval_if_valid(&mut self) -> u32 {
+    pub fn eval_if_valid(&mut self) -> Result<u32> {
         if self.is_valid() {
             self.eval()
         } else {
-            0
+            Ok(0)
         }
     }
 
     /// Drain the stack and evaluate the result.
-    pub fn eval(&mut self) -> u32 {
+    pub fn eval(&mut self) -> Result<u32> {
+        const TWO_NUMBERS: Error =
+            Error::InvalidAssumption("`mul` has two numbers separated by a comma");
+
         let [lhs, rhs] = std::mem::take(&mut self.stack)
             .split(',')
             .map(|str| {
                 str.chars()
-                    .filter(|c| c.is_numeric())
+                    .filter(char::is_ascii_digit)
                     .collect::<String>()
                     .parse::<u32>()
-                    .unwrap()
+                    .map_err(|_| TWO_NUMBERS)
             })
             .take(2) // There should only ever be two, but it doesn't hurt.
-            .collect::<Vec<_>>()[..]
+            .collect::<Result<Vec<_>>>()?[..]
         else {
-            panic!("encountered a stack without two numbers separated by a comma")
+            return Err(TWO_NUMBERS);
         };
 
-        lhs * rhs
+        lhs.checked_mul(rhs).ok_or(Error::Overflow)
     }
 }
 
@@ -106,7 +111,7 @@ impl Stack<MulPattern> for MulStack {
             Some('u') => MulPattern::Char('l'),
             Some('l') => MulPattern::Char('('),
             Some('(') => MulPattern::Number,
-            Some(c) if c.is_numeric() => {
+            Some(c) if c.is_ascii_digit() => {
                 let is_lhs = !self.as_str().contains(',');
 
                 if self.current_num_length() == *NUM_LEN.end() {
@@ -123,7 +128,7 @@ impl Stack<MulPattern> for MulStack {
             }
             Some(',') => MulPattern::Number,
             Some(')') => MulPattern::None,
-            _ => panic!("bad character in stack"),
+            _ => unreachable!("`push_or_clear` only pushes characters matching `ideal_next`"),
         }
     }
 
@@ -148,7 +153,7 @@ impl Stack<MulPattern> for MulStack {
     }
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Eq, Debug)]
 pub enum MulPattern {
     Char(char),
     Number,
@@ -162,9 +167,9 @@ impl PartialEq<char> for MulPattern {
     fn eq(&self, other: &char) -> bool {
         match self {
             Self::Char(c) => c == other,
-            Self::Number => other.is_numeric(),
-            Self::NumberOrComma => *other == ',' || other.is_numeric(),
-            Self::NumberOrCloseParen => *other == ')' || other.is_numeric(),
+            Self::Number => other.is_ascii_digit(),
+            Self::NumberOrComma => *other == ',' || other.is_ascii_digit(),
+            Self::NumberOrCloseParen => *other == ')' || other.is_ascii_digit(),
             Self::Comma => *other == ',',
             Self::None => false,
         }
@@ -190,7 +195,7 @@ impl Stack<DoPattern> for DoStack {
             Some('o') => DoPattern::OpenParen,
             Some('(') => DoPattern::CloseParen,
             Some(')') => DoPattern::None,
-            _ => panic!("bad character in stack"),
+            _ => unreachable!("`push_or_clear` only pushes characters matching `ideal_next`"),
         }
     }
 
@@ -215,7 +220,7 @@ impl Stack<DoPattern> for DoStack {
     }
 }
 
-#[derive(PartialEq, Debug, Clone, Copy)]
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub enum DoPattern {
     D,
     O,
@@ -264,7 +269,7 @@ impl Stack<DontPattern> for DontStack {
             Some('t') => DontPattern::OpenParen,
             Some('(') => DontPattern::CloseParen,
             Some(')') => DontPattern::None,
-            _ => panic!("bad character in stack"),
+            _ => unreachable!("`push_or_clear` only pushes characters matching `ideal_next`"),
         }
     }
 
@@ -289,7 +294,7 @@ impl Stack<DontPattern> for DontStack {
     }
 }
 
-#[derive(PartialEq, Debug, Clone, Copy)]
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub enum DontPattern {
     D,
     O,
@@ -1,7 +1,10 @@
 #![allow(unused)]
 
+use crate::error::{Error, Result};
+
 /// A grid of characters. Every line is guaranteed to be of the same length.
 #[derive(PartialEq, Eq, Clone, Debug)]
+#[expect(clippy::struct_field_names, reason = "`Grid::grid` is the grid itself")]
 pub struct Grid {
     // Using [`Box`] instead of [`std::rc::Rc`] because [`Self::get_row`] is the only option that
     // could benefit.
@@ -11,36 +14,41 @@ pub struct Grid {
 }
 
 impl Grid {
-    pub fn new(input: &str) -> Option<Self> {
-        let init: Option<usize> = None;
-
+    /// Parse a grid from lines of characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no lines, or if they are not all the same length.
+    pub fn new(input: &str) -> Result<Self> {
         // Check that every row is of the same length and get that length.
-        let columns = input.lines().try_fold(None, |prev, row| match prev {
-            None => Some(Some(row.len())),
-            Some(prev_len) => {
-                if prev_len == row.len() {
-                    Some(Some(prev_len))
-                } else {
-                    None
-                }
-            }
-        })??;
+        let columns = input
+            .lines()
+            .enumerate()
+            .try_fold(None::<usize>, |prev, (line_index, row)| match prev {
+                Some(prev_len) if prev_len != row.len() => Err(Error::parse(
+                    line_index,
+                    prev_len.min(row.len()),
+                    "every row must be the same length",
+                )),
+                _ => Ok(Some(row.len())),
+            })?
+            .ok_or(Error::parse(0, 0, "expected at least one row"))?;
 
         let rows = input.lines().count();
         let grid = input.lines().map(Into::into).collect();
 
-        Some(Self {
+        Ok(Self {
             grid,
-            rows,
             columns,
+            rows,
         })
     }
 
-    pub fn columns(&self) -> usize {
+    pub const fn columns(&self) -> usize {
         self.columns
     }
 
-    pub fn rows(&self) -> usize {
+    pub const fn rows(&self) -> usize {
         self.rows
     }
 
@@ -157,20 +165,27 @@ impl Grid {
         count >= 2
     }
 
-    pub fn search_all_cross(&self, pattern: &str) -> u32 {
-        self.char_indices()
+    /// Count every index at which [`Self::match_cross`] matches `pattern`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the count does not fit in a [`u32`].
+    pub fn search_all_cross(&self, pattern: &str) -> Result<u32> {
+        Ok(self
+            .char_indices()
             .filter(|(index, _)| self.match_cross(*index, pattern))
             .count()
-            .try_into()
-            // Safety: None, I'm letting this one crash. It's not intended for matching on the
-            // scale of [`u32::MAX`].
-            .unwrap()
+            .try_into()?)
     }
 
     pub fn chars(&self) -> impl Iterator<Item = char> + use<'_> {
         self.grid.iter().flat_map(|row| row.chars())
     }
 
+    #[expect(
+        clippy::missing_panics_doc,
+        reason = "the indices of `self.grid` are always within its bounds"
+    )]
     pub fn char_indices(&self) -> impl Iterator<Item = (GridIndex, char)> + use<'_> {
         // Assumes that these will never change.
         let max_column_index = self.columns() - 1;
@@ -200,7 +215,7 @@ pub struct GridIndex {
 }
 
 impl GridIndex {
-    pub fn new(column: usize, row: usize, max_column: usize, max_row: usize) -> Option<Self> {
+    pub const fn new(column: usize, row: usize, max_column: usize, max_row: usize) -> Option<Self> {
         if column <= max_column && row <= max_row {
             Some(Self {
                 column,
@@ -222,23 +237,23 @@ impl GridIndex {
         )
     }
 
-    pub fn row(&self) -> usize {
+    pub const fn row(&self) -> usize {
         self.row
     }
 
-    pub fn column(&self) -> usize {
+    pub const fn column(&self) -> usize {
         self.column
     }
 
-    pub fn max_row(&self) -> usize {
+    pub const fn max_row(&self) -> usize {
         self.max_row
     }
 
-    pub fn max_column(&self) -> usize {
+    pub const fn max_column(&self) -> usize {
         self.max_column
     }
 
-    pub fn step_row(&self, step: isize) -> Option<Self> {
+    pub const fn step_row(&self, step: isize) -> Option<Self> {
         let next = self.row().wrapping_add_signed(step);
 
         if next <= self.max_row() {
@@ -261,7 +276,7 @@ impl GridIndex {
     pub fn step(&self, steps: usize, direction: Direction) -> Option<Self> {
         // Parameter is `usize` to avoid negative values, for which [`Direction::reverse`] should
         // be used instead.
-        let steps = steps as isize;
+        let steps = isize::try_from(steps).ok()?;
 
         match direction {
             Direction::North => self.step_row(-steps),
@@ -289,8 +304,8 @@ pub enum Direction {
 }
 
 impl Direction {
-    pub fn reverse(&self) -> Self {
-        use Direction::*;
+    pub const fn reverse(self) -> Self {
+        use Direction::{East, North, Northeast, Northwest, South, Southeast, Southwest, West};
 
         match self {
             North => South,
@@ -306,7 +321,7 @@ impl Direction {
 
     /// Every option for [`Self`]. Makes no guarantees regarding ordering.
     pub fn all() -> Box<[Self]> {
-        use Direction::*;
+        use Direction::{East, North, Northeast, Northwest, South, Southeast, Southwest, West};
 
         Box::new([
             North, South, East, West, Northeast, Northwest, Southeast, Southwest,
@@ -315,14 +330,14 @@ impl Direction {
 
     /// The cardinal directions in [`Self`]. Makes no guarantees regarding ordering.
     pub fn cardinal() -> Box<[Self]> {
-        use Direction::*;
+        use Direction::{East, North, South, West};
 
         Box::new([North, South, East, West])
     }
 
     /// The non-cardinal directions in [`Self`]. Makes no guarantees regarding ordering.
     pub fn ordinal() -> Box<[Self]> {
-        use Direction::*;
+        use Direction::{Northeast, Northwest, Southeast, Southwest};
 
         Box::new([Northeast, Northwest, Southeast, Southwest])
     }
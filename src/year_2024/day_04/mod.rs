@@ -1,9 +1,14 @@
-mod grid;
+pub mod grid;
 #[cfg(test)]
 mod test;
 
-const INPUT: &str = include_str!("./data.txt");
-const _INPUT: &str = "MMMSXXMASM
+use crate::{
+    error::Result,
+    solution::{Answer, Solution},
+};
+
+#[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]
+const EXAMPLE_INPUT: &str = "MMMSXXMASM
 MSAMXMSMSA
 AMXSXMAAMM
 MSAMASMSMX
@@ -14,12 +19,29 @@ SAXAMASAAA
 MAMMMXMMMM
 MXMXAXMASX";
 
-pub fn part_one() -> u32 {
-    const PATTERN: &str = "XMAS";
+pub struct Day;
+
+impl Solution for Day {
+    type Input = grid::Grid;
+
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        grid::Grid::new(input)
+    }
+
+    fn part_one(grid: &Self::Input) -> Result<Answer> {
+        const PATTERN: &str = "XMAS";
+
+        Ok(grid.search_all(PATTERN).into())
+    }
 
-    grid::Grid::new(INPUT).unwrap().search_all(PATTERN)
+    fn part_two(grid: &Self::Input) -> Result<Answer> {
+        Ok(grid.search_all_cross("MAS")?.into())
+    }
 }
 
-pub fn part_two() -> u32 {
-    grid::Grid::new(INPUT).unwrap().search_all_cross("MAS")
+crate::solution::examples! {
+    EXAMPLE_INPUT => { part_one: 18, part_two: 9 },
 }
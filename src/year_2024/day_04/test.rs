@@ -9,7 +9,7 @@ type Result = std::result::Result<(), Box<dyn std::error::Error>>;
 
 #[test]
 fn test_directional() -> Result {
-    let grid = Grid::new(GRID).ok_or("failed to construct grid")?;
+    let grid = Grid::new(GRID)?;
     let origin = GridIndex::from_grid(0, 0, &grid).ok_or("failed to construct index (0,0)")?;
     let last = GridIndex::from_grid(3, 3, &grid).ok_or("failed to construct index (3,3)")?;
 
@@ -67,7 +67,7 @@ fn test_grid_iter() -> Result {
             ]
         };
     }
-    let grid = Grid::new("012\n345").ok_or("failed to construct grid")?;
+    let grid = Grid::new("012\n345")?;
 
     assert_eq!(
         {
@@ -118,12 +118,7 @@ SAXAMASAAA
 MAMMMXMMMM
 MXMXAXMASX";
 
-    assert_eq!(
-        9,
-        Grid::new(INPUT)
-            .ok_or("failed to construct grid")?
-            .search_all_cross("MAS")
-    );
+    assert_eq!(9, Grid::new(INPUT)?.search_all_cross("MAS")?);
 
     Ok(())
 }
@@ -1,10 +1,15 @@
 use updates::Updates;
 
-mod updates;
+use crate::{
+    error::{Error, Result},
+    solution::{Answer, Solution},
+    Integer,
+};
 
-#[allow(unused)]
-const INPUT: &str = include_str!("./data.txt");
-const _INPUT: &str = "47|53
+pub mod updates;
+
+#[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]
+const EXAMPLE_INPUT: &str = "47|53
 97|13
 97|61
 97|47
@@ -33,33 +38,56 @@ const _INPUT: &str = "47|53
 61,13,29
 97,13,75,29,47";
 
-pub fn part_one() -> u32 {
-    let updates = Updates::from_str(_INPUT).unwrap();
+pub struct Day;
 
-    assert!(updates.sorted_updates().count() > 0);
+impl Solution for Day {
+    type Input = Updates<u32>;
 
-    updates
-        .sorted_updates()
-        // Take the sum of all the middle values.
-        .filter_map(|update| update.get(update.len() / 2))
-        .sum()
-}
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part_one(updates: &Self::Input) -> Result<Answer> {
+        if updates.sorted_updates().next().is_none() {
+            return Err(Error::InvalidAssumption(
+                "at least one update is already sorted",
+            ));
+        }
 
-pub fn part_two() -> u32 {
-    let mut updates: Updates<u32> = Updates::from_str(INPUT).unwrap();
-    let rules = updates.rules().clone(); // Evil
+        Ok(updates
+            .sorted_updates()
+            // Take the sum of all the middle values.
+            .filter_map(|update| update.get(update.len() / 2))
+            .map(|&value| Integer::from(value))
+            .sum::<Integer>()
+            .into())
+    }
+
+    fn part_two(updates: &Self::Input) -> Result<Answer> {
+        let mut updates = updates.clone();
+        let rules = updates.rules().clone(); // Evil
+
+        #[expect(
+            clippy::manual_inspect,
+            reason = "need a mutable reference, which `inspect` does not provide"
+        )]
+        Ok(updates
+            .unsorted_updates_mut()
+            .map(|update| {
+                update.sort(&rules);
+                update
+            })
+            // Take the sum of all the middle values.
+            .filter_map(|update| update.get(update.len() / 2))
+            .map(|&value| Integer::from(value))
+            .sum::<Integer>()
+            .into())
+    }
+}
 
-    #[expect(
-        clippy::manual_inspect,
-        reason = "need a mutable reference, which `inspect` does not provide"
-    )]
-    updates
-        .unsorted_updates_mut()
-        .map(|update| {
-            update.sort(&rules);
-            update
-        })
-        // Take the sum of all the middle values.
-        .filter_map(|update| update.get(update.len() / 2))
-        .sum()
+crate::solution::examples! {
+    EXAMPLE_INPUT => { part_one: 143, part_two: 123 },
 }
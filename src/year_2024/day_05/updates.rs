@@ -5,7 +5,9 @@ use std::{
     str::FromStr,
 };
 
-#[derive(Debug)]
+use crate::error::{self, Error, Result};
+
+#[derive(Debug, Clone)]
 pub struct Updates<T: Ord + Hash + FromStr + Copy + Debug> {
     /// For a given value, store a map of other values and whether they are greater or lesser than the
     /// given value.
@@ -14,41 +16,10 @@ pub struct Updates<T: Ord + Hash + FromStr + Copy + Debug> {
 }
 
 impl<T: Ord + Hash + FromStr + Copy + Debug> Updates<T> {
-    pub fn rules(&self) -> &RulesMap<T> {
+    pub const fn rules(&self) -> &RulesMap<T> {
         &self.rules
     }
 
-    pub fn from_str(input: &str) -> Option<Self> {
-        let mut lines = input.lines();
-        let mut rules = RulesMap::new();
-
-        // Parse rules. Expecting lines of `01|23`.
-        for line in lines.by_ref() {
-            let Some((lhs, rhs)) = line.split_once('|') else {
-                // Rules are ended with a blank line.
-                break;
-            };
-
-            let lhs: T = lhs.parse().ok()?;
-            let rhs: T = rhs.parse().ok()?;
-
-            rules.push_or_insert(lhs, rhs);
-        }
-
-        // Parse updates. Expecting lines of `01,23,45,67`.
-        let updates = lines
-            .map(|line| {
-                line.split(',')
-                    .flat_map(str::parse)
-                    .collect::<Vec<_>>()
-                    .into()
-            })
-            .collect::<Vec<Update<T>>>()
-            .into_boxed_slice();
-
-        Some(Self { rules, updates })
-    }
-
     pub fn sorted_updates(&self) -> impl Iterator<Item = &Update<T>> {
         self.updates
             .iter()
@@ -69,11 +40,54 @@ impl<T: Ord + Hash + FromStr + Copy + Debug> Updates<T> {
     }
 }
 
-/// For a given value of type [`T`], store a set of values that should be sorted to its left or to
+impl<T: Ord + Hash + FromStr + Copy + Debug> FromStr for Updates<T> {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let mut lines = input.lines().enumerate();
+        let mut rules = RulesMap::default();
+
+        // Parse rules. Expecting lines of `01|23`.
+        for (line_index, line) in lines.by_ref() {
+            let Some((lhs, rhs)) = line.split_once('|') else {
+                // Rules are ended with a blank line.
+                break;
+            };
+
+            let rhs_column = lhs.len() + 1;
+            let lhs: T = lhs
+                .parse()
+                .map_err(|_| Error::parse(line_index, 0, "expected a number before `|`"))?;
+            let rhs: T = rhs
+                .parse()
+                .map_err(|_| Error::parse(line_index, rhs_column, "expected a number after `|`"))?;
+
+            rules.push_or_insert(lhs, rhs);
+        }
+
+        // Parse updates. Expecting lines of `01,23,45,67`.
+        let updates = lines
+            .map(|(line_index, line)| {
+                error::split_columns(line, ',')
+                    .map(|(column, str)| {
+                        str.parse().map_err(|_| {
+                            Error::parse(line_index, column, "expected numbers separated by `,`")
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()
+                    .map(Into::into)
+            })
+            .collect::<Result<Box<[Update<T>]>>>()?;
+
+        Ok(Self { rules, updates })
+    }
+}
+
+/// For a given value of type `T`, store a set of values that should be sorted to its left or to
 /// its right.
 ///
-/// Does not actually store the value of [`T`], as this is design for used in a [`HashMap`], as in
-/// [`RuleMap`].
+/// Does not actually store the value of `T`, as this is design for used in a [`HashMap`], as in
+/// [`RulesMap`].
 #[derive(Debug, Clone)]
 pub struct Rules<T: Ord + Hash + Copy + Debug> {
     /// Every value that should be placed to the left of a value.
@@ -83,14 +97,6 @@ pub struct Rules<T: Ord + Hash + Copy + Debug> {
 }
 
 impl<T: Ord + Hash + Copy + Debug> Rules<T> {
-    #[allow(unused)]
-    pub fn new() -> Self {
-        Self {
-            left: HashSet::new(),
-            right: HashSet::new(),
-        }
-    }
-
     pub fn from_left(left: T) -> Self {
         Self {
             left: HashSet::from([left]),
@@ -105,9 +111,13 @@ impl<T: Ord + Hash + Copy + Debug> Rules<T> {
         }
     }
 
-    /// Returns whether [`rhs`] should be to the left or the right of the value associated with
+    /// Returns whether `rhs` should be to the left or the right of the value associated with
     /// [`Self`], or [`None`] if there is no rule.
     #[allow(unused)]
+    #[expect(
+        clippy::should_implement_trait,
+        reason = "compares against a value, rather than other rules"
+    )]
     pub fn cmp(&self, rhs: &T) -> Option<Ordering> {
         if self.left().contains(rhs) {
             return Some(Ordering::Left);
@@ -120,19 +130,19 @@ impl<T: Ord + Hash + Copy + Debug> Rules<T> {
         None
     }
 
-    pub fn left(&self) -> &HashSet<T> {
+    pub const fn left(&self) -> &HashSet<T> {
         &self.left
     }
 
-    pub fn left_mut(&mut self) -> &mut HashSet<T> {
+    pub const fn left_mut(&mut self) -> &mut HashSet<T> {
         &mut self.left
     }
 
-    pub fn right(&self) -> &HashSet<T> {
+    pub const fn right(&self) -> &HashSet<T> {
         &self.right
     }
 
-    pub fn right_mut(&mut self) -> &mut HashSet<T> {
+    pub const fn right_mut(&mut self) -> &mut HashSet<T> {
         &mut self.right
     }
 }
@@ -142,13 +152,15 @@ pub struct RulesMap<T: Ord + Hash + Copy + Debug> {
     inner: HashMap<T, Rules<T>>,
 }
 
-impl<T: Ord + Hash + Copy + Debug> RulesMap<T> {
-    pub fn new() -> Self {
+impl<T: Ord + Hash + Copy + Debug> Default for RulesMap<T> {
+    fn default() -> Self {
         Self {
             inner: HashMap::new(),
         }
     }
+}
 
+impl<T: Ord + Hash + Copy + Debug> RulesMap<T> {
     pub fn get(&self, key: &T) -> Option<&Rules<T>> {
         self.inner.get(key)
     }
@@ -174,7 +186,7 @@ impl<T: Ord + Hash + Copy + Debug> RulesMap<T> {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Update<T: Ord + Hash + Copy + Debug> {
     inner: Box<[T]>,
 }
@@ -188,6 +200,10 @@ impl<T: Ord + Hash + Copy + Debug> Update<T> {
         self.inner.len()
     }
 
+    pub const fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
     /// Prone to stack overflows.
     pub fn sorted(&self, rules: &RulesMap<T>) -> Sorted {
         let update = &self.inner;
@@ -250,8 +266,7 @@ impl<T: Ord + Hash + Copy + Debug> Update<T> {
 
     pub fn sort(&mut self, rules: &RulesMap<T>) {
         let (unsorted_index, cause_index) = match self.sorted(rules) {
-            Sorted::Sorted => return,
-            Sorted::MissingRule { index: _ } => return,
+            Sorted::Sorted | Sorted::MissingRule { index: _ } => return,
             Sorted::Unsorted {
                 unsorted_index,
                 cause_index,
@@ -273,7 +288,6 @@ impl<T: Ord + Hash + Copy + Debug> From<Vec<T>> for Update<T> {
 }
 
 #[derive(Debug)]
-#[expect(unused)]
 pub enum Sorted {
     Sorted,
     Unsorted {
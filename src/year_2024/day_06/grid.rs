@@ -1,5 +1,10 @@
 use std::{fmt::Display, rc::Rc};
 
+use crate::{
+    cancel,
+    error::{self, Error},
+};
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Guard {
     grid: Grid,
@@ -7,20 +12,23 @@ pub struct Guard {
 }
 
 impl Guard {
-    pub fn new(input: &str) -> Option<Self> {
+    pub fn new(input: &str) -> error::Result<Self> {
         let (grid, location) = Grid::new(input)?;
 
-        Some(Self { grid, location })
+        Ok(Self { grid, location })
     }
 
     /// # Errors
     ///
-    /// Returns [`None`] if the coordinates as [`isize`] overflow.
-    pub fn all_locations(&self) -> Option<Box<[Coord]>> {
+    /// Returns [`Error::Overflow`] if the coordinates as [`isize`] overflow, or
+    /// [`Error::TimedOut`] if cancelled, as the guard never leaves if it walks in a loop.
+    pub fn all_locations(&self) -> error::Result<Box<[Coord]>> {
         let mut locations = vec![self.coord()];
 
         let mut next = self.step();
         loop {
+            cancel::check()?;
+
             match next {
                 Ok(g) => {
                     if !g
@@ -34,11 +42,11 @@ impl Guard {
                     next = g.step();
                 }
                 Err(AddError::OutOfBounds) => break,
-                Err(AddError::Overflow) => return None,
+                Err(AddError::Overflow) => return Err(Error::Overflow),
             }
         }
 
-        Some(locations.into_boxed_slice())
+        Ok(locations.into_boxed_slice())
     }
 
     pub fn step(&self) -> Result<Self, AddError> {
@@ -61,7 +69,7 @@ impl Guard {
         })
     }
 
-    pub fn coord(&self) -> Coord {
+    pub const fn coord(&self) -> Coord {
         self.location.coord()
     }
 }
@@ -76,13 +84,24 @@ pub struct Grid {
 }
 
 impl Grid {
-    pub fn new(input: &str) -> Option<(Self, GuardLocation)> {
-        let mut lines = input.lines();
-        let columns = input.lines().next()?.len();
+    pub fn new(input: &str) -> error::Result<(Self, GuardLocation)> {
+        let columns = input
+            .lines()
+            .next()
+            .ok_or(Error::parse(0, 0, "expected at least one row"))?
+            .len();
 
         // Check that every row is of the same length.
-        if lines.any(|s| s.len() != columns) {
-            return None;
+        if let Some((line_index, line)) = input
+            .lines()
+            .enumerate()
+            .find(|(_, line)| line.len() != columns)
+        {
+            return Err(Error::parse(
+                line_index,
+                line.len().min(columns),
+                "every row must be the same length",
+            ));
         }
 
         let rows = input.lines().count();
@@ -104,26 +123,32 @@ impl Grid {
                 }
 
                 match char {
-                    '.' => continue,
+                    '.' => (),
                     '#' => row.push(Coord::new(char_index, line_index)),
                     '^' => guard!(North),
                     'v' => guard!(South),
                     '>' => guard!(East),
                     '<' => guard!(West),
-                    _ => return None,
+                    _ => {
+                        return Err(Error::parse(
+                            line_index,
+                            char_index,
+                            "expected one of `.`, `#`, `^`, `v`, `>`, or `<`",
+                        ))
+                    }
                 }
             }
 
             grid.push(row);
         }
 
-        Some((
+        Ok((
             Self {
                 obstacles: grid.into_iter().map(Into::into).collect(),
                 columns,
                 rows,
             },
-            guard?,
+            guard.ok_or(Error::InvalidAssumption("the grid contains a guard"))?,
         ))
     }
 
@@ -131,11 +156,11 @@ impl Grid {
         self.obstacles.clone()
     }
 
-    pub fn columns(&self) -> usize {
+    pub const fn columns(&self) -> usize {
         self.columns
     }
 
-    pub fn rows(&self) -> usize {
+    pub const fn rows(&self) -> usize {
         self.rows
     }
 
@@ -145,7 +170,7 @@ impl Grid {
             return false;
         };
 
-        row.iter().any(|&c| c == coord)
+        row.contains(&coord)
     }
 }
 
@@ -156,7 +181,7 @@ pub struct GuardLocation {
 }
 
 impl GuardLocation {
-    pub fn new(coord: Coord, direction: Direction) -> Self {
+    pub const fn new(coord: Coord, direction: Direction) -> Self {
         Self { coord, direction }
     }
 
@@ -167,18 +192,18 @@ impl GuardLocation {
         })
     }
 
-    pub fn rotate(&self) -> Self {
+    pub const fn rotate(&self) -> Self {
         Self {
             coord: self.coord,
             direction: self.direction.rotate(),
         }
     }
 
-    pub fn coord(&self) -> Coord {
+    pub const fn coord(&self) -> Coord {
         self.coord
     }
 
-    pub fn direction(&self) -> Direction {
+    pub const fn direction(&self) -> Direction {
         self.direction
     }
 }
@@ -190,7 +215,7 @@ pub struct Coord {
 }
 
 impl Coord {
-    pub fn new(column: usize, row: usize) -> Self {
+    pub const fn new(column: usize, row: usize) -> Self {
         Self { column, row }
     }
 
@@ -220,15 +245,15 @@ impl Coord {
         })
     }
 
-    pub fn is_within_bounds(&self, columns: usize, row: usize) -> bool {
+    pub const fn is_within_bounds(&self, columns: usize, row: usize) -> bool {
         self.column < columns && self.row < row
     }
 
-    pub fn column(&self) -> usize {
+    pub const fn column(&self) -> usize {
         self.column
     }
 
-    pub fn row(&self) -> usize {
+    pub const fn row(&self) -> usize {
         self.row
     }
 }
@@ -249,12 +274,12 @@ pub enum Direction {
 
 impl Direction {
     /// Rotate 90 degrees.
-    pub fn rotate(&self) -> Self {
+    pub const fn rotate(self) -> Self {
         match self {
-            Direction::North => Direction::East,
-            Direction::South => Direction::West,
-            Direction::East => Direction::South,
-            Direction::West => Direction::North,
+            Self::North => Self::East,
+            Self::South => Self::West,
+            Self::East => Self::South,
+            Self::West => Self::North,
         }
     }
 }
@@ -264,3 +289,17 @@ pub enum AddError {
     OutOfBounds,
     Overflow,
 }
+
+#[cfg(test)]
+mod test {
+    use super::{super::EXAMPLE_INPUT, Guard};
+    use crate::snapshot::assert_snapshot;
+
+    #[test]
+    fn display() {
+        let locations = Guard::new(EXAMPLE_INPUT).unwrap().all_locations().unwrap();
+        let locations: Vec<String> = locations.iter().map(ToString::to_string).collect();
+
+        assert_snapshot!("locations", locations.join("\n"));
+    }
+}
@@ -4,9 +4,13 @@ use std::collections::HashSet;
 
 use grid::{Coord, Guard};
 
+use crate::{
+    error::{Error, Result},
+    solution::{Answer, Solution},
+};
+
 mod grid;
 
-const INPUT: &str = include_str!("./data.txt");
 const EXAMPLE_INPUT: &str = "....#.....
 .........#
 ..........
@@ -18,31 +22,33 @@ const EXAMPLE_INPUT: &str = "....#.....
 #.........
 ......#...";
 
-pub fn part_one() -> u32 {
-    part_one_impl(INPUT)
-}
+pub struct Day;
 
-fn part_one_impl(input: &str) -> u32 {
-    let guard = Guard::new(input).unwrap();
+impl Solution for Day {
+    type Input = Guard;
 
-    HashSet::<Coord>::from_iter(
-        guard
-            .all_locations()
-            .expect("reasonably-sized grids won't cause `isize` overflows")
-            .iter()
-            .copied(),
-    )
-    .len()
-    .try_into()
-    .unwrap()
-}
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Guard::new(input)
+    }
 
-#[cfg(test)]
-mod test {
-    use super::{part_one_impl, EXAMPLE_INPUT};
+    fn part_one(guard: &Self::Input) -> Result<Answer> {
+        Ok(guard
+            .all_locations()?
+            .iter()
+            .copied()
+            .collect::<HashSet<Coord>>()
+            .len()
+            .into())
+    }
 
-    #[test]
-    fn part_one() {
-        assert_eq!(41, part_one_impl(EXAMPLE_INPUT));
+    fn part_two(_guard: &Self::Input) -> Result<Answer> {
+        Err(Error::Unimplemented)
     }
 }
+
+crate::solution::examples! {
+    EXAMPLE_INPUT => { part_one: 41 },
+}
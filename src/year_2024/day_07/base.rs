@@ -6,7 +6,7 @@ pub struct Base {
 }
 
 impl Base {
-    pub fn new(base: usize) -> Option<Self> {
+    pub const fn new(base: usize) -> Option<Self> {
         if base > Operation::base() {
             return None;
         }
@@ -14,11 +14,11 @@ impl Base {
         Some(Self { base })
     }
 
-    pub fn get(&self) -> usize {
+    pub const fn get(self) -> usize {
         self.base
     }
 
-    pub fn int_to_operations(&self, mut int: usize, length: usize) -> Vec<Operation> {
+    pub fn int_to_operations(self, mut int: usize, length: usize) -> Vec<Operation> {
         let mut operations = Vec::with_capacity(length);
 
         while int > 0 {
@@ -1,4 +1,8 @@
-use crate::Integer;
+use crate::{
+    cancel,
+    error::{Error, Result},
+    Integer,
+};
 
 use super::base::Base;
 
@@ -9,58 +13,74 @@ pub struct Equation {
 }
 
 impl Equation {
-    pub fn new(expected_value: Integer, inputs: Box<[Integer]>) -> Self {
+    pub const fn new(expected_value: Integer, inputs: Box<[Integer]>) -> Self {
         Self {
             expected_value,
             inputs,
         }
     }
 
-    pub fn expected_value(&self) -> Integer {
+    pub const fn expected_value(&self) -> Integer {
         self.expected_value
     }
 
     /// Tests all possible combinations of [`Operation::Add`] and [`Operation::Multiply`] on
     /// [`Self::inputs`] to see if any match [`Self::expected_value`]. If any match, return `true`,
     /// else `false`.
-    pub fn is_valid_binary(&self) -> bool {
+    pub fn is_valid_binary(&self) -> Result<bool> {
         self.is_valid(Base::new(2).expect("2 is a valid `Base`"))
     }
 
     /// Tests all possible combinations of [`Operation`]s on [`Self::inputs`] to see if any match
     /// [`Self::expected_value`]. If any match, return `true`, else `false`.
-    pub fn is_valid_ternary(&self) -> bool {
+    pub fn is_valid_ternary(&self) -> Result<bool> {
         self.is_valid(Base::new(3).expect("3 is a valid `Base`"))
     }
 
-    fn is_valid(&self, base: Base) -> bool {
+    fn is_valid(&self, base: Base) -> Result<bool> {
         match self.inputs.len() {
-            0 => return false,
-            1 => return self.expected_value == *self.inputs.first().expect("`inputs` is length 1"),
+            0 => return Ok(false),
+            1 => {
+                return Ok(
+                    self.expected_value == *self.inputs.first().expect("`inputs` is length 1")
+                )
+            }
             _ => (),
         }
 
         let operations = self.inputs.len() - 1;
+        let combinations = base
+            .get()
+            .checked_pow(operations.try_into()?)
+            .ok_or(Error::Overflow)?;
 
-        for i in 0..base.get().pow(operations as u32) {
+        'combinations: for i in 0..combinations {
+            cancel::check()?;
             let mut operations = base.int_to_operations(i, operations);
 
             // Applies the `operations` on `self.inputs`.
             let mut iter = self.inputs.iter();
             let mut acculumated = *iter.next().expect("`inputs` is length >1");
             for value in iter {
-                acculumated = operations
+                let Some(next) = operations
                     .pop()
                     .expect("`operations` is `inputs.len() - 1` in a loop of `inputs.len() - 1`")
-                    .apply(acculumated, *value);
+                    .apply(acculumated, *value)
+                else {
+                    // No operation decreases the accumulated value, so once it overflows, it can
+                    // never match `self.expected_value`.
+                    continue 'combinations;
+                };
+
+                acculumated = next;
             }
 
             if acculumated == self.expected_value {
-                return true;
+                return Ok(true);
             }
         }
 
-        false
+        Ok(false)
     }
 }
 
@@ -79,15 +99,29 @@ impl Operation {
         3
     }
 
-    pub fn apply(&self, lhs: Integer, rhs: Integer) -> Integer {
+    /// Returns [`None`] if the result overflows.
+    pub const fn apply(self, lhs: Integer, rhs: Integer) -> Option<Integer> {
         match self {
-            Self::Add => lhs + rhs,
-            Self::Multiply => lhs * rhs,
-            Self::Concatenate => lhs * (10 as Integer).pow(rhs.ilog10() + 1) + rhs,
+            Self::Add => lhs.checked_add(rhs),
+            Self::Multiply => lhs.checked_mul(rhs),
+            Self::Concatenate => {
+                let digits = match rhs.checked_ilog10() {
+                    Some(log) => log + 1,
+                    None => 1,
+                };
+
+                match (10 as Integer).checked_pow(digits) {
+                    Some(shift) => match lhs.checked_mul(shift) {
+                        Some(shifted) => shifted.checked_add(rhs),
+                        None => None,
+                    },
+                    None => None,
+                }
+            }
         }
     }
 
-    pub fn from_digit(digit: usize) -> Option<Self> {
+    pub const fn from_digit(digit: usize) -> Option<Self> {
         match digit {
             0 => Some(Self::Add),
             1 => Some(Self::Multiply),
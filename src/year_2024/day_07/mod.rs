@@ -1,12 +1,14 @@
 mod base;
 mod equation;
 
-use crate::Integer;
+use crate::{
+    error::{self, Error, Result},
+    solution::{Answer, Solution},
+    Integer,
+};
 use equation::Equation;
 
-#[allow(dead_code)]
-const INPUT: &str = include_str!("./data.txt");
-#[allow(dead_code)]
+#[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]
 const EXAMPLE_INPUT: &str = "190: 10 19
 3267: 81 40 27
 83: 17 5
@@ -17,49 +19,75 @@ const EXAMPLE_INPUT: &str = "190: 10 19
 21037: 9 7 18 13
 292: 11 6 16 20";
 
-pub fn part_one() -> Integer {
-    let equations = parse_input(INPUT).unwrap();
+pub struct Day;
 
-    sum_valid(&equations, Equation::is_valid_binary)
-}
+impl Solution for Day {
+    type Input = Box<[Equation]>;
+
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
 
-pub fn part_two() -> Integer {
-    let equations = parse_input(INPUT).unwrap();
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(equations: &Self::Input) -> Result<Answer> {
+        sum_valid(equations, Equation::is_valid_binary).map(Into::into)
+    }
 
-    sum_valid(&equations, Equation::is_valid_ternary)
+    fn part_two(equations: &Self::Input) -> Result<Answer> {
+        sum_valid(equations, Equation::is_valid_ternary).map(Into::into)
+    }
 }
 
-fn sum_valid(equations: &[Equation], mut validate: impl FnMut(&Equation) -> bool) -> Integer {
-    equations
-        .iter()
-        .filter_map(move |e| {
-            if !validate(e) {
-                return None;
-            }
-
-            Some(e.expected_value())
-        })
-        .sum()
+fn sum_valid(
+    equations: &[Equation],
+    mut validate: impl FnMut(&Equation) -> Result<bool>,
+) -> Result<Integer> {
+    equations.iter().try_fold(0, |sum: Integer, e| {
+        if !validate(e)? {
+            return Ok(sum);
+        }
+
+        sum.checked_add(e.expected_value()).ok_or(Error::Overflow)
+    })
 }
 
-fn parse_input(input: &str) -> Option<Box<[Equation]>> {
+fn parse_input(input: &str) -> Result<Box<[Equation]>> {
     // Instinctually, I want to instantiate with `Vec::with_capacity(input.lines().count());`, but
     // I assume that's probably `O(n)`.
     let mut equations: Vec<Equation> = vec![];
 
     // Expecting input like `3267: 81 40 27`.
-    for line in input.lines() {
-        let (expected_value, inputs) = line.split_once(": ")?;
+    for (line_index, line) in input.lines().enumerate() {
+        let (expected_value, inputs) = line
+            .split_once(": ")
+            .ok_or_else(|| Error::parse(line_index, 0, "expected `: `"))?;
+
+        let expected_value: Integer = expected_value
+            .parse()
+            .map_err(|_| Error::parse(line_index, 0, "expected a number before `: `"))?;
 
-        let expected_value: Integer = expected_value.parse().ok()?;
-        let inputs: Box<[Integer]> = inputs
-            .split(' ')
-            // Should this ignore errors like this?
-            .filter_map(|n| n.parse().ok())
-            .collect();
+        // The inputs start after the expected value and `: `.
+        let offset = line.len() - inputs.len();
+        let inputs: Box<[Integer]> = error::split_columns(inputs, ' ')
+            .map(|(column, n)| {
+                n.parse().map_err(|_| {
+                    Error::parse(
+                        line_index,
+                        offset + column,
+                        "expected numbers separated by spaces",
+                    )
+                })
+            })
+            .collect::<Result<_>>()?;
 
         equations.push(Equation::new(expected_value, inputs));
     }
 
-    Some(equations.into_boxed_slice())
+    Ok(equations.into_boxed_slice())
+}
+
+crate::solution::examples! {
+    EXAMPLE_INPUT => { part_one: 3749, part_two: 11387 },
 }
@@ -1,12 +1,13 @@
-mod radio;
+pub mod radio;
 
 use radio::Radios;
 
-use crate::Integer;
+use crate::{
+    error::Result,
+    solution::{Answer, Solution},
+};
 
-#[allow(dead_code)]
-const INPUT: &str = include_str!("./data.txt");
-#[allow(dead_code)]
+#[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]
 const EXAMPLE_INPUT: &str = "............
 ........0...
 .....0......
@@ -20,21 +21,34 @@ const EXAMPLE_INPUT: &str = "............
 ............
 ............";
 
-// - Each antenna is tuned to a frequency
-// - A frequency is a single lowercase letter, uppercase letter, or digit
-// - An Antinode accepts a particular frequency
-// - An antinode occurs on any point on a line with two antennas at equal frequencies, where the
-//   second antenna is twice as far away
-// - Except where it would overflow off the map, each antenna pair creates two antinodes
-// - Antinodes can occur at the same location as another antenna
-pub fn part_one() -> Integer {
-    let radios = Radios::parse(INPUT).unwrap();
-
-    radios.antinode_pairs().len().try_into().unwrap()
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Radios;
+
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
 
-pub fn part_two() -> Integer {
-    let radios = Radios::parse(INPUT).unwrap();
+    fn parse(input: &str) -> Result<Self::Input> {
+        Radios::parse(input)
+    }
+
+    // - Each antenna is tuned to a frequency
+    // - A frequency is a single lowercase letter, uppercase letter, or digit
+    // - An Antinode accepts a particular frequency
+    // - An antinode occurs on any point on a line with two antennas at equal frequencies, where
+    //   the second antenna is twice as far away
+    // - Except where it would overflow off the map, each antenna pair creates two antinodes
+    // - Antinodes can occur at the same location as another antenna
+    fn part_one(radios: &Self::Input) -> Result<Answer> {
+        Ok(radios.antinode_pairs().len().into())
+    }
+
+    fn part_two(radios: &Self::Input) -> Result<Answer> {
+        Ok(radios.all_antinodes().len().into())
+    }
+}
 
-    radios.all_antinodes().len().try_into().unwrap()
+crate::solution::examples! {
+    EXAMPLE_INPUT => { part_one: 14, part_two: 34 },
 }
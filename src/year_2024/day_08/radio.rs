@@ -4,7 +4,13 @@ use std::{
     fmt::Display,
 };
 
+use crate::error::{Error, Result};
+
 #[derive(Debug, PartialEq, Eq)]
+#[expect(
+    clippy::struct_field_names,
+    reason = "`Radios::radios` is the radios themselves"
+)]
 pub struct Radios {
     radios: HashMap<Frequency, Vec<Location>>,
     columns: usize,
@@ -12,20 +18,33 @@ pub struct Radios {
 }
 
 impl Radios {
-    pub fn parse(input: &str) -> Option<Self> {
+    /// Parse a map of radios, where each letter or digit is a radio of that frequency.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no rows, or if they are not all the same length.
+    pub fn parse(input: &str) -> Result<Self> {
         // Is this `O(n)`? Should it be handled in the for loop?
         let rows = input.lines().count();
-        let columns = input.lines().next()?.len();
+        let columns = input
+            .lines()
+            .next()
+            .ok_or(Error::parse(0, 0, "expected at least one row"))?
+            .len();
 
         let mut radios: HashMap<Frequency, Vec<Location>> = HashMap::new();
 
         for (row_index, line) in input.lines().enumerate() {
-            for (column_index, char) in line.char_indices() {
-                // Expecting all rows to be of the same length.
-                if column_index > columns - 1 {
-                    return None;
-                }
+            // Expecting all rows to be of the same length.
+            if line.len() != columns {
+                return Err(Error::parse(
+                    row_index,
+                    line.len().min(columns),
+                    "every row must be the same length",
+                ));
+            }
 
+            for (column_index, char) in line.char_indices() {
                 let Some(frequency) = Frequency::new(char) else {
                     continue;
                 };
@@ -39,23 +58,24 @@ impl Radios {
                     None => {
                         radios.insert(frequency, vec![radio]);
                     }
-                };
+                }
             }
         }
 
-        Some(Self {
+        Ok(Self {
             radios,
             columns,
             rows,
         })
     }
 
+    #[allow(unused)]
     pub fn from_pairs(input: Vec<(Frequency, Location)>) -> Self {
         let mut radios = HashMap::<Frequency, Vec<Location>>::new();
         let mut columns = 0;
         let mut rows = 0;
 
-        for (frequency, radio) in input.into_iter() {
+        for (frequency, radio) in input {
             columns = columns.max(radio.column() + 1);
             rows = rows.max(radio.row() + 1);
 
@@ -66,7 +86,7 @@ impl Radios {
                 None => {
                     radios.insert(frequency, vec![radio]);
                 }
-            };
+            }
         }
 
         Self {
@@ -76,6 +96,7 @@ impl Radios {
         }
     }
 
+    #[allow(unused)]
     pub fn from_pairs_bounded(
         input: Vec<(Frequency, Location)>,
         columns: usize,
@@ -151,7 +172,7 @@ impl Radios {
                         continue;
                     }
 
-                    for antinode in radio.all_antinodes(other, self.columns, self.rows).iter() {
+                    for antinode in &radio.all_antinodes(other, self.columns, self.rows) {
                         locations.insert(*antinode);
                     }
                 }
@@ -214,7 +235,7 @@ impl Location {
         Self { column, row }
     }
 
-    pub fn is_in_bounds(&self, columns: usize, rows: usize) -> bool {
+    pub const fn is_in_bounds(&self, columns: usize, rows: usize) -> bool {
         self.column() < columns && self.row() < rows
     }
 
@@ -268,18 +289,22 @@ impl Location {
         Some(Self { column, row })
     }
 
-    pub fn rise_run(&self, other: &Self) -> (isize, isize) {
+    #[expect(
+        clippy::cast_possible_wrap,
+        reason = "a grid wide enough to wrap would not fit in memory"
+    )]
+    pub const fn rise_run(&self, other: &Self) -> (isize, isize) {
         let rise = other.row() as isize - self.row() as isize;
         let run = other.column() as isize - self.column() as isize;
 
         (rise, run)
     }
 
-    pub fn column(&self) -> usize {
+    pub const fn column(&self) -> usize {
         self.column
     }
 
-    pub fn row(&self) -> usize {
+    pub const fn row(&self) -> usize {
         self.row
     }
 
@@ -305,7 +330,7 @@ pub struct Frequency {
 }
 
 impl Frequency {
-    pub fn new(frequency: char) -> Option<Self> {
+    pub const fn new(frequency: char) -> Option<Self> {
         if !frequency.is_ascii_alphanumeric() {
             return None;
         }
@@ -313,7 +338,7 @@ impl Frequency {
         Some(Self { frequency })
     }
 
-    pub fn get(&self) -> char {
+    pub const fn get(self) -> char {
         self.frequency
     }
 }
@@ -326,7 +351,60 @@ impl Display for Frequency {
 
 #[cfg(test)]
 mod test {
-    use super::Location;
+    use proptest::prelude::*;
+
+    use super::{super::EXAMPLE_INPUT, Location, Radios};
+    use crate::{property, snapshot::assert_snapshot};
+
+    /// A map of a few frequencies of radios, at least one row and column in size.
+    fn map() -> impl Strategy<Value = String> {
+        (1..=12_usize, 1..=12_usize).prop_flat_map(|(columns, rows)| {
+            let cell = prop::sample::select(vec!['.', '.', '.', '.', 'a', 'A', '0']);
+
+            prop::collection::vec(
+                prop::collection::vec(cell, columns).prop_map(String::from_iter),
+                rows,
+            )
+            .prop_map(|rows| rows.join("\n"))
+        })
+    }
+
+    proptest! {
+        #![proptest_config(property::config())]
+
+        #[test]
+        fn parse_from_pairs_and_display_round_trip(map in map()) {
+            let radios = Radios::parse(&map).unwrap();
+
+            // Every row is displayed with a trailing newline.
+            prop_assert_eq!(format!("{map}\n"), radios.to_string());
+            let from_pairs =
+                Radios::from_pairs_bounded(radios.radio_pairs(), radios.columns, radios.rows);
+            prop_assert_eq!(Some(radios), from_pairs);
+        }
+
+        #[test]
+        fn antinodes_are_in_bounds(map in map()) {
+            let radios = Radios::parse(&map).unwrap();
+            let antinodes = radios.all_antinodes();
+
+            prop_assert!(antinodes
+                .iter()
+                .all(|antinode| antinode.is_in_bounds(radios.columns, radios.rows)));
+            prop_assert!(radios.antinode_pairs().is_subset(&antinodes));
+        }
+    }
+
+    #[test]
+    fn display() {
+        let radios = Radios::parse(EXAMPLE_INPUT).unwrap();
+        assert_snapshot!("radios", radios);
+
+        let mut antinodes: Vec<Location> = radios.all_antinodes().into_iter().collect();
+        antinodes.sort_unstable_by(Location::cmp_by_row);
+        let antinodes: Vec<String> = antinodes.iter().map(ToString::to_string).collect();
+        assert_snapshot!("antinodes", antinodes.join("\n"));
+    }
 
     #[test]
     fn test_antinode() {
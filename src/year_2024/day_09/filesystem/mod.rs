@@ -3,7 +3,10 @@ mod test;
 
 use std::{fmt::Display, sync::Mutex};
 
-use crate::Integer;
+use crate::{
+    error::{Error, Result},
+    Integer,
+};
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
 pub struct Filesystem {
@@ -13,7 +16,11 @@ pub struct Filesystem {
 #[expect(unused, reason = "in-progress")]
 impl Filesystem {
     /// Parse from the official format from Advent of Code.
-    pub fn parse(input: &str) -> Self {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input contains anything other than digits.
+    pub fn parse(input: &str) -> Result<Self> {
         enum Next {
             File { id: usize },
             Empty { id: usize },
@@ -22,10 +29,16 @@ impl Filesystem {
         let mut spans = vec![];
 
         let mut next = Next::File { id: 0 };
-        for len in input
-            .chars()
-            .filter_map(|c| c.to_digit(10).and_then(|d| d.try_into().ok()))
-        {
+        let lens = input.lines().enumerate().flat_map(|(line_index, line)| {
+            line.char_indices().map(move |(column_index, c)| {
+                c.to_digit(10)
+                    .and_then(|d| d.try_into().ok())
+                    .ok_or_else(|| Error::parse(line_index, column_index, "expected a digit"))
+            })
+        });
+        for len in lens {
+            let len = len?;
+
             if len != 0 {
                 spans.push(match next {
                     Next::File { id } => Span::File(File { id, len }),
@@ -39,7 +52,7 @@ impl Filesystem {
             }
         }
 
-        Self { spans }
+        Ok(Self { spans })
     }
 
     /// Parse from the [`Display`] format of [`Self`].
@@ -85,7 +98,7 @@ impl Filesystem {
             }
             (Span::Empty(_), Span::Empty(_)) => *last.len_mut() += value.len(),
             _ => self.spans.push(value),
-        };
+        }
     }
 
     fn append(&mut self, spans: Vec<Span>) {
@@ -169,7 +182,7 @@ impl Filesystem {
                 Some(spans.remove(0))
             }
 
-            pub fn fs_mut(&self) -> std::sync::MutexGuard<Filesystem> {
+            pub fn fs_mut(&self) -> std::sync::MutexGuard<'_, Filesystem> {
                 self.fs.lock().unwrap()
             }
         }
@@ -248,7 +261,7 @@ impl Filesystem {
                 self.fs_mut().spans.insert(0, span);
             }
 
-            pub fn fs_mut(&self) -> std::sync::MutexGuard<Filesystem> {
+            pub fn fs_mut(&self) -> std::sync::MutexGuard<'_, Filesystem> {
                 self.fs.lock().unwrap()
             }
         }
@@ -262,27 +275,31 @@ impl Filesystem {
         while let Some(span) = fs.next() {
             match span {
                 Span::File(_) => spans.push(span),
-                Span::Empty(mut empty) => {
-                    match fs.pop_fitting(empty.len()) {
-                        Some(fitting_span) => {
-                            spans.push(fitting_span);
-                            *empty.len_mut() -= fitting_span.len();
-                            fs.insert_front(Span::Empty(empty));
-                        }
-                        None => spans.push(span),
-                    };
-                }
+                Span::Empty(mut empty) => match fs.pop_fitting(empty.len()) {
+                    Some(fitting_span) => {
+                        spans.push(fitting_span);
+                        *empty.len_mut() -= fitting_span.len();
+                        fs.insert_front(Span::Empty(empty));
+                    }
+                    None => spans.push(span),
+                },
             }
         }
 
         spans
     }
 
-    pub fn checksum(&self) -> Integer {
+    /// The sum of each file block's position multiplied by its file's ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the checksum does not fit in an [`Integer`].
+    pub fn checksum(&self) -> Result<Integer> {
         // Tracks the actual block-level index in the filesystem.
         let mut block_index = 0;
 
-        self.spans
+        Ok(self
+            .spans
             .iter()
             // For every file,
             .filter_map(|s| {
@@ -307,8 +324,7 @@ impl Filesystem {
                 }
             })
             .sum::<usize>()
-            .try_into()
-            .unwrap()
+            .try_into()?)
     }
 }
 
@@ -336,6 +352,10 @@ impl File {
         self.len
     }
 
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
     pub const fn len_mut(&mut self) -> &mut usize {
         &mut self.len
     }
@@ -351,6 +371,10 @@ impl Empty {
         self.len
     }
 
+    pub const fn is_empty(self) -> bool {
+        self.len == 0
+    }
+
     pub const fn len_mut(&mut self) -> &mut usize {
         &mut self.len
     }
@@ -369,6 +393,10 @@ impl Span {
         }
     }
 
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     pub const fn len_mut(&mut self) -> &mut usize {
         match self {
             Self::Empty(Empty { len }) | Self::File(File { len, .. }) => len,
@@ -382,13 +410,15 @@ impl Span {
         }
     }
 
-    pub fn try_as_file_mut(&mut self) -> Option<&mut File> {
+    #[allow(unused)]
+    pub const fn try_as_file_mut(&mut self) -> Option<&mut File> {
         match self {
             Self::File(file) => Some(file),
             Self::Empty(_) => None,
         }
     }
 
+    #[allow(unused)]
     pub const fn try_to_empty(&self) -> Option<Empty> {
         match *self {
             Self::Empty(empty) => Some(empty),
@@ -1,14 +1,47 @@
+use proptest::prelude::*;
+
 use super::{super::EXAMPLE_INPUT, Empty, File, Filesystem, Span};
+use crate::{property, snapshot::assert_snapshot};
+
+/// A disk map of up to ten files, so that every file ID displays as a single digit. Files are never
+/// empty, as an empty file would split the free space around it into two spans.
+fn disk_map() -> impl Strategy<Value = String> {
+    prop::collection::vec((1..=9_u8, 0..=9_u8), 1..=10).prop_map(|lens| {
+        lens.iter()
+            .flat_map(|&file_and_empty| <[u8; 2]>::from(file_and_empty))
+            .map(|len| char::from(b'0' + len))
+            .collect()
+    })
+}
+
+fn filesystem() -> impl Strategy<Value = Filesystem> {
+    disk_map().prop_map(|disk_map| Filesystem::parse(&disk_map).expect("disk maps are digits"))
+}
+
+/// Every file block of a displayed filesystem, sorted.
+fn file_blocks(displayed: &str) -> Vec<char> {
+    let mut blocks: Vec<char> = displayed.chars().filter(|&c| c != '.').collect();
+    blocks.sort_unstable();
+    blocks
+}
 
 #[test]
 fn parse_display_and_deserialize() {
     let expected = "00...111...2...333.44.5555.6666.777.888899";
-    let parsed = Filesystem::parse(EXAMPLE_INPUT);
+    let parsed = Filesystem::parse(EXAMPLE_INPUT).unwrap();
 
     assert_eq!(expected, parsed.to_string());
     assert_eq!(parsed, Filesystem::deserialize(expected));
 }
 
+#[test]
+fn display() {
+    let fs = Filesystem::parse(EXAMPLE_INPUT).unwrap();
+
+    assert_snapshot!("compact", fs.to_compact());
+    assert_snapshot!("defragmented", fs.to_defragmented());
+}
+
 #[test]
 fn pop() {
     let mut fs = Filesystem::deserialize("00...111...2...333.44.5555.6666.777.888899");
@@ -202,5 +235,52 @@ fn checksum() {
         ],
     };
 
-    assert_eq!(1928, fs.checksum());
+    assert_eq!(Ok(1928), fs.checksum());
+}
+
+proptest! {
+    #![proptest_config(property::config())]
+
+    #[test]
+    fn parse_display_and_deserialize_round_trip(disk_map in disk_map()) {
+        let fs = Filesystem::parse(&disk_map).unwrap();
+        let displayed = fs.to_string();
+
+        let blocks: u32 = disk_map.chars().filter_map(|c| c.to_digit(10)).sum();
+        prop_assert_eq!(blocks as usize, displayed.len());
+        prop_assert_eq!(fs, Filesystem::deserialize(&displayed));
+    }
+
+    #[test]
+    fn to_compact_keeps_every_file_block(fs in filesystem()) {
+        let displayed = fs.to_string();
+        let compact = fs.to_compact().to_string();
+
+        prop_assert_eq!(file_blocks(&displayed), file_blocks(&compact));
+        // No free space is left between files.
+        prop_assert!(!compact.trim_end_matches('.').contains('.'), "{compact}");
+    }
+
+    #[test]
+    fn to_defragmented_only_moves_whole_files_left(fs in filesystem()) {
+        let displayed = fs.to_string();
+        let defragmented = fs.to_defragmented().to_string();
+
+        prop_assert_eq!(displayed.len(), defragmented.len());
+        prop_assert_eq!(file_blocks(&displayed), file_blocks(&defragmented));
+
+        for id in ('0'..='9').filter(|&id| displayed.contains(id)) {
+            let (Some(before), Some(start), Some(end)) = (
+                displayed.find(id),
+                defragmented.find(id),
+                defragmented.rfind(id),
+            ) else {
+                unreachable!("every file is kept, as checked above");
+            };
+            let len = displayed.matches(id).count();
+
+            prop_assert!(start <= before, "file {id} moved right: {defragmented}");
+            prop_assert_eq!(len, end - start + 1, "file {} was split: {}", id, defragmented);
+        }
+    }
 }
@@ -1,20 +1,36 @@
-mod filesystem;
+pub mod filesystem;
 
 use filesystem::Filesystem;
 
-use crate::Integer;
+use crate::{
+    error::Result,
+    solution::{Answer, Solution},
+};
 
-const INPUT: &str = include_str!("./data.txt");
+#[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]
 const EXAMPLE_INPUT: &str = "2333133121414131402";
 
-pub fn part_one() -> Integer {
-    let fs = Filesystem::parse(INPUT);
+pub struct Day;
 
-    fs.to_compact().checksum()
-}
+impl Solution for Day {
+    type Input = Filesystem;
+
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 9;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Filesystem::parse(input)
+    }
 
-pub fn part_two() -> Integer {
-    let fs = Filesystem::parse(INPUT);
+    fn part_one(fs: &Self::Input) -> Result<Answer> {
+        fs.to_compact().checksum().map(Into::into)
+    }
+
+    fn part_two(fs: &Self::Input) -> Result<Answer> {
+        fs.to_defragmented().checksum().map(Into::into)
+    }
+}
 
-    fs.to_defragmented().checksum()
+crate::solution::examples! {
+    EXAMPLE_INPUT => { part_one: 1928, part_two: 2858 },
 }
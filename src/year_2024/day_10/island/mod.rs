@@ -1,10 +1,13 @@
-mod position;
+pub mod position;
 #[cfg(test)]
 mod test;
 
 use position::{Coordinates, Direction, Height, Position};
 
-use crate::Integer;
+use crate::{
+    error::{Error, Result},
+    Integer,
+};
 
 use std::{collections::HashSet, fmt::Display};
 
@@ -16,22 +19,37 @@ pub struct Island {
 }
 
 impl Island {
-    pub fn parse(input: &str) -> Option<Self> {
+    /// Parse a map of heights from `0` to `9`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a height is not a digit, or if the rows are not all the same length.
+    pub fn parse(input: &str) -> Result<Self> {
         let mut grid = vec![];
 
-        let mut columns = None;
+        let mut columns: Option<usize> = None;
 
-        for line in input.lines() {
+        for (line_index, line) in input.lines().enumerate() {
             let mut row = vec![];
 
-            for char in line.chars() {
-                row.push(Height::new(char.to_digit(10)?.try_into().ok()?)?);
+            for (column_index, char) in line.char_indices() {
+                row.push(
+                    char.to_digit(10)
+                        .and_then(|digit| Height::new(digit.try_into().ok()?))
+                        .ok_or_else(|| {
+                            Error::parse(line_index, column_index, "expected a height from 0 to 9")
+                        })?,
+                );
             }
 
             match columns {
                 Some(num) => {
                     if num != row.len() {
-                        return None;
+                        return Err(Error::parse(
+                            line_index,
+                            num.min(row.len()),
+                            "every row must be the same length",
+                        ));
                     }
                 }
                 None => columns = Some(row.len()),
@@ -41,24 +59,22 @@ impl Island {
         }
 
         let rows = grid.len();
-        let columns = columns?;
 
-        if rows < 1 || columns < 1 {
-            return None;
-        }
+        let Some(columns) = columns.filter(|&columns| rows >= 1 && columns >= 1) else {
+            return Err(Error::parse(0, 0, "expected at least one row and column"));
+        };
 
-        Some(Self {
+        Ok(Self {
             grid: grid.into_boxed_slice(),
             columns,
             rows,
         })
     }
 
-    #[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]
     pub fn new(input: Vec<Vec<u8>>) -> Option<Self> {
         let mut grid = vec![];
 
-        let mut columns = None;
+        let mut columns: Option<usize> = None;
 
         for row in input {
             let mut new_row = vec![];
@@ -63,6 +63,12 @@ impl Coordinates {
         Self { column, row }
     }
 
+    /// The coordinates one step in `direction` from these.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AddError::OutOfBounds`] if the step would go below zero, or
+    /// [`AddError::Overflow`] if it would overflow.
     pub fn step(&self, direction: Direction) -> Result<Self, AddError> {
         // This is nasty. There's got to be a better way!
         fn add(unsigned: usize, signed: isize) -> Result<usize, AddError> {
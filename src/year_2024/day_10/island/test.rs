@@ -1,6 +1,9 @@
 use std::collections::HashSet;
 
-use super::{super::EXAMPLE_INPUT, Coordinates, Island};
+use proptest::prelude::*;
+
+use super::{super::EXAMPLE_INPUT, position::Height, Coordinates, Island};
+use crate::{property, snapshot::assert_snapshot};
 
 macro_rules! island {
     [ $( $($height:expr),+ ; )+ ] => {
@@ -14,6 +17,13 @@ macro_rules! island {
     };
 }
 
+/// A grid of valid heights, with at least one row and column.
+fn heights() -> impl Strategy<Value = Vec<Vec<u8>>> {
+    (1..=8_usize, 1..=8_usize).prop_flat_map(|(columns, rows)| {
+        prop::collection::vec(prop::collection::vec(0..=Height::MAX, columns), rows)
+    })
+}
+
 #[test]
 fn island_parse_display() {
     let island = Island::parse(EXAMPLE_INPUT).unwrap();
@@ -30,6 +40,17 @@ fn island_parse_display() {
     );
 }
 
+#[test]
+fn display() {
+    let island = island![
+        9, 9, 0, 9, 9;
+        5, 6, 1, 8, 7;
+        4, 3, 2, 1, 0;
+    ];
+
+    assert_snapshot!("island", island);
+}
+
 #[test]
 fn trailheads() {
     let island = island![
@@ -57,3 +78,20 @@ fn trailheads() {
         ])
     );
 }
+
+proptest! {
+    #![proptest_config(property::config())]
+
+    #[test]
+    fn parse_new_and_display_round_trip(heights in heights()) {
+        let displayed = heights
+            .iter()
+            .map(|row| row.iter().map(ToString::to_string).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let island = Island::new(heights).unwrap();
+
+        prop_assert_eq!(&displayed, &island.to_string());
+        prop_assert_eq!(island, Island::parse(&displayed).unwrap());
+    }
+}
@@ -1,23 +1,49 @@
-mod island;
+pub mod island;
 
 use island::Island;
 
-use crate::Integer;
+use crate::{
+    error::Result,
+    solution::{Answer, Solution},
+};
 
-const INPUT: &str = include_str!("./data.txt");
+#[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]
 const EXAMPLE_INPUT: &str = "0123
 1234
 8765
 9876";
+#[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]
+const LARGE_EXAMPLE_INPUT: &str = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
 
-pub fn part_one() -> Integer {
-    let island = Island::parse(INPUT).unwrap();
+pub struct Day;
 
-    island.count_all_trail_endpoints()
-}
+impl Solution for Day {
+    type Input = Island;
+
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Island::parse(input)
+    }
 
-pub fn part_two() -> Integer {
-    let island = Island::parse(INPUT).unwrap();
+    fn part_one(island: &Self::Input) -> Result<Answer> {
+        Ok(island.count_all_trail_endpoints().into())
+    }
+
+    fn part_two(island: &Self::Input) -> Result<Answer> {
+        Ok(island.count_all_trails().into())
+    }
+}
 
-    island.count_all_trails()
+crate::solution::examples! {
+    EXAMPLE_INPUT => { part_one: 1 },
+    LARGE_EXAMPLE_INPUT => { part_one: 36, part_two: 81 },
 }
@@ -1,22 +1,42 @@
-mod stones;
+pub mod stones;
 
 use stones::Stones;
 
-use crate::Integer;
+use crate::{
+    error::Result,
+    solution::{Answer, Solution},
+};
 
-const INPUT: &str = include_str!("./data.txt");
+#[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]
 const EXAMPLE_INPUT: &str = "125 17";
 
-pub fn part_one() -> Integer {
-    let mut stones = Stones::parse(INPUT).unwrap();
-    stones.blink_n(25);
+pub struct Day;
 
-    stones.len() as Integer
-}
+impl Solution for Day {
+    type Input = Stones;
+
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Stones::parse(input)
+    }
+
+    fn part_one(stones: &Self::Input) -> Result<Answer> {
+        let mut stones = stones.clone();
+        stones.blink_n(25);
 
-pub fn part_two() -> Integer {
-    let mut stones = Stones::parse(INPUT).unwrap();
-    stones.blink_n(75);
+        Ok(stones.len().into())
+    }
+
+    fn part_two(stones: &Self::Input) -> Result<Answer> {
+        let mut stones = stones.clone();
+        stones.blink_n(75);
+
+        Ok(stones.len().into())
+    }
+}
 
-    stones.len() as Integer
+crate::solution::examples! {
+    EXAMPLE_INPUT => { part_one: 55312 },
 }
@@ -1,9 +1,12 @@
 #[cfg(test)]
 mod test;
 
-use std::{cell::RefCell, collections::HashMap, fmt::Display, time::Instant};
+use std::{cell::RefCell, collections::HashMap, fmt::Display};
 
-use crate::Integer;
+use crate::{
+    error::{self, Error, Result},
+    Integer,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Stones {
@@ -11,21 +14,29 @@ pub struct Stones {
 }
 
 impl Stones {
-    pub fn parse(input: &str) -> Option<Self> {
+    /// Parse a line of numbers separated by spaces.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a stone is not a number.
+    pub fn parse(input: &str) -> Result<Self> {
         let mut stones = HashMap::new();
 
-        for str in input.split(' ').map(str::trim_ascii_end) {
-            increment_or_insert_n(&mut stones, Stone::new(str.parse::<Integer>().ok()?), 1);
+        for (column, str) in error::split_columns(input.trim_ascii_end(), ' ') {
+            let stone = str
+                .parse::<Integer>()
+                .map_err(|_| Error::parse(0, column, "expected numbers separated by spaces"))?;
+
+            increment_or_insert_n(&mut stones, Stone::new(stone), 1);
         }
 
-        Some(Self {
+        Ok(Self {
             stones: StoneMaps::new(stones),
         })
     }
 
     pub fn blink_n(&mut self, blinks: usize) {
-        let now = Instant::now();
-        for i in 0..blinks {
+        for _ in 0..blinks {
             self.stones.blink();
         }
     }
@@ -34,9 +45,12 @@ impl Stones {
         self.stones.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// For large maps, this can be obscene amounts of memory! For the example input, this is 476
     /// TiB of memory!
-    #[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]
     pub fn as_slice(&self) -> Box<[Stone]> {
         self.stones.as_slice()
     }
@@ -58,7 +72,7 @@ fn increment_or_insert_n<K: Eq + std::hash::Hash>(
         None => {
             map.insert(key, count);
         }
-    };
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -137,6 +151,7 @@ impl StoneMaps {
         self.drain().values().sum()
     }
 
+    #[allow(unused)]
     pub fn unique_len(&self) -> usize {
         self.drain().len()
     }
@@ -156,18 +171,25 @@ impl StoneMaps {
 
 impl Display for StoneMaps {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut stones = self
+        // Sorted, as the maps have no order of their own to display in.
+        let mut counts = self
             .drain()
+            .iter()
+            .map(|(&stone, &count)| (stone, count))
+            .collect::<Vec<_>>();
+        counts.sort_unstable();
+
+        let mut stones = counts
             .iter()
             .map(|(stone, count)| {
-                // E.g., `"25, 25, 25, "`.
+                // E.g., `"25 25 25 "`.
                 (stone.to_string() + " ").repeat(*count)
             })
             .collect::<String>();
 
         assert_eq!(stones.pop(), Some(' '));
 
-        // E.g., `"25, 25, 25, 43, 43"`.
+        // E.g., `"25 25 25 43 43"`.
         write!(f, "{stones}")
     }
 }
@@ -199,7 +221,7 @@ impl Stone {
 
         let len = self.number().ilog10() + 1;
 
-        if len % 2 == 0 {
+        if len.is_multiple_of(2) {
             // E.g., `1234` -> `12`.
             let left = self.number() / (10 as Integer).pow(len / 2);
             // E.g., `1234` -> `34`.
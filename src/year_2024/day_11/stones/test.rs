@@ -1,8 +1,9 @@
-use super::Stones;
+use super::{super::EXAMPLE_INPUT, Stones};
+use crate::snapshot::assert_snapshot;
 
 #[test]
 fn blink() {
-    let mut stones = Stones::parse("125 17").unwrap();
+    let mut stones = Stones::parse(EXAMPLE_INPUT).unwrap();
 
     macro_rules! next_blink {
         ($stones:expr, $expected:expr) => {
@@ -41,3 +42,17 @@ fn blink() {
 
     assert_eq!(22, stones.len());
 }
+
+#[test]
+fn display() {
+    let mut stones = Stones::parse(EXAMPLE_INPUT).unwrap();
+    let blinks: Vec<String> = (0..=6)
+        .map(|_| {
+            let line = stones.to_string();
+            stones.blink_n(1);
+            line
+        })
+        .collect();
+
+    assert_snapshot!("blinks", blinks.join("\n"));
+}
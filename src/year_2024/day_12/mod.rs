@@ -1,29 +1,31 @@
-mod plot;
+pub mod plot;
 
 use plot::Plot;
 
-use crate::Integer;
+use crate::{
+    error::Result,
+    solution::{Answer, Solution},
+};
 
-const INPUT: &str = include_str!("./data.txt");
-/// Expects [`part_one`] to return `140` and [`part_two`] to return `80`.
+#[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]
 const EXAMPLE_INPUT: &str = "AAAA
 BBCD
 BBCC
 EEEC";
-/// Expects [`part_one`] to return `772`.
+#[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]
 const SIMPLE_EXAMPLE_INPUT: &str = "OOOOO
 OXOXO
 OOOOO
 OXOXO
 OOOOO";
-/// Expects [`part_two`] to return `368`.
+#[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]
 const SIMPLE_EXAMPLE_INPUT_PART_TWO: &str = "AAAAAA
 AAABBA
 AAABBA
 ABBAAA
 ABBAAA
 AAAAAA";
-/// Expects [`part_one`] to return `1930` and [`part_two`] to return `1206`.
+#[cfg_attr(not(test), expect(dead_code, reason = "used in tests"))]
 const LARGE_EXAMPLE_INPUT: &str = "RRRRIICCFF
 RRRRIICCCF
 VVRRRCCFFF
@@ -35,14 +37,30 @@ MIIIIIJJEE
 MIIISIJEEE
 MMMISSJEEE";
 
-pub fn part_one() -> Integer {
-    let plot = Plot::parse(INPUT).unwrap();
+pub struct Day;
 
-    plot.fencing_quote()
-}
+impl Solution for Day {
+    type Input = Plot;
+
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 12;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Plot::parse(input)
+    }
 
-pub fn part_two() -> Integer {
-    let plot = Plot::parse(INPUT).unwrap();
+    fn part_one(plot: &Self::Input) -> Result<Answer> {
+        Ok(plot.fencing_quote().into())
+    }
+
+    fn part_two(plot: &Self::Input) -> Result<Answer> {
+        Ok(plot.fencing_quote_bulk().into())
+    }
+}
 
-    plot.fencing_quote_bulk()
+crate::solution::examples! {
+    EXAMPLE_INPUT => { part_one: 140, part_two: 80 },
+    SIMPLE_EXAMPLE_INPUT => { part_one: 772 },
+    SIMPLE_EXAMPLE_INPUT_PART_TWO => { part_two: 368 },
+    LARGE_EXAMPLE_INPUT => { part_one: 1930, part_two: 1206 },
 }
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, fmt::Write};
+use std::collections::HashMap;
 
 use crate::Integer;
 
@@ -138,6 +138,11 @@ impl<'a> BulkGrid<'a> {
 
     /// Directly constructs [`Self`] with a pre-defined list of regions. Strictly for the purposes
     /// of simpler test cases.
+    ///
+    /// # Safety
+    ///
+    /// The grid is left empty, so `regions` are not checked against it, and visiting any
+    /// coordinates finds nothing.
     #[cfg(test)]
     pub unsafe fn with_regions(
         regions: Vec<(Integer, HashMap<Coordinates, Vec<Direction>>)>,
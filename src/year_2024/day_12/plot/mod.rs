@@ -1,12 +1,15 @@
-mod grid;
-mod places;
+pub mod grid;
+pub mod places;
 #[cfg(test)]
 mod test;
 
 use grid::{BulkGrid, StandardGrid};
 use places::{Coordinates, Plant};
 
-use crate::Integer;
+use crate::{
+    error::{Error, Result},
+    Integer,
+};
 
 use std::hash::Hash;
 
@@ -18,22 +21,33 @@ pub struct Plot {
 }
 
 impl Plot {
-    pub fn parse(input: &str) -> Result<Self, ParseError> {
+    /// Parse a map of plants, where each character is a type of plant.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a plant is `0`, or if the rows are not all the same length.
+    pub fn parse(input: &str) -> Result<Self> {
         let mut grid = vec![];
 
-        let mut columns = None;
+        let mut columns: Option<usize> = None;
 
-        for line in input.lines() {
+        for (line_index, line) in input.lines().enumerate() {
             let mut row = vec![];
 
-            for char in line.chars() {
-                row.push(Plant::new(char).ok_or(ParseError::InvalidPlant)?);
+            for (column_index, char) in line.char_indices() {
+                row.push(Plant::new(char).ok_or_else(|| {
+                    Error::parse(line_index, column_index, "`0` is not a valid plant")
+                })?);
             }
 
             match columns {
                 Some(num) => {
                     if num != row.len() {
-                        return Err(ParseError::UnevenGrid);
+                        return Err(Error::parse(
+                            line_index,
+                            num.min(row.len()),
+                            "every row must be the same length",
+                        ));
                     }
                 }
                 None => columns = Some(row.len()),
@@ -46,7 +60,7 @@ impl Plot {
         let columns = columns.unwrap_or(0);
 
         if rows < 1 || columns < 1 {
-            return Err(ParseError::EmptyGrid);
+            return Err(Error::parse(0, 0, "expected at least one row and column"));
         }
 
         Ok(Self {
@@ -86,13 +100,3 @@ impl Plot {
             .sum()
     }
 }
-
-#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq)]
-pub enum ParseError {
-    /// When the grid is not at least one row and one column.
-    EmptyGrid,
-    /// When a rows in the grid has a different length than the first row.
-    UnevenGrid,
-    /// When a character fails to pass [`Plant::new`].
-    InvalidPlant,
-}
@@ -39,6 +39,12 @@ impl Coordinates {
         Self { column, row }
     }
 
+    /// The coordinates one step in `direction` from these.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AddError::OutOfBounds`] if the step would go below zero, or
+    /// [`AddError::Overflow`] if it would overflow.
     pub fn step(&self, direction: Direction) -> Result<Self, AddError> {
         // This is nasty. There's got to be a better way!
         fn add(unsigned: usize, signed: isize) -> Result<usize, AddError> {
@@ -80,8 +86,8 @@ pub enum AddError {
 
 /// Represents a span between two [`Coordinates`], with one exposed edge ([`Direction`]).
 ///
-/// It is guaranteed to share one location or run along one [`Axis`], such both [`Self::start`] and
-/// [`Self::end`] share the same [`Coordinates::column`], the same [`Coordinates::row`], or both.
+/// It is guaranteed to share one location or run along one [`Axis`], such both `start` and
+/// `end` share the same [`Coordinates::column`], the same [`Coordinates::row`], or both.
 /// The [`Axis`] runs perpendicular to [`Self::exposed_edge`].
 #[derive(Clone, Copy, Hash, Debug, PartialEq, Eq)]
 pub struct Span {
@@ -139,8 +145,8 @@ impl Span {
         Some(Axis::Horizontal)
     }
 
-    /// Measures the [`Direction`] of an arrow pointing from [`Self::start`] to [`Self::end`].
-    /// Returns [`None`] if [`Self::start`] and [`Self::end`] are at the same [`Coordinates`].
+    /// Measures the [`Direction`] of an arrow pointing from `start` to `end`.
+    /// Returns [`None`] if `start` and `end` are at the same [`Coordinates`].
     pub fn direction(&self) -> Option<Direction> {
         match self.start.column.cmp(&self.end.column) {
             std::cmp::Ordering::Less => return Some(Direction::East),
@@ -155,15 +161,15 @@ impl Span {
         }
     }
 
-    /// Measures the [`Direction`] of an arrow pointing from [`Self::start`] to [`Self::end`].
-    /// Returns [`Axis::direction_positive`] if [`Self::start`] and [`Self::end`] are at the same
+    /// Measures the [`Direction`] of an arrow pointing from `start` to `end`.
+    /// Returns [`Axis::direction_positive`] if `start` and `end` are at the same
     /// [`Coordinates`] and thus do not have a [`Direction`] of their own.
     pub fn direction_or_positive(&self) -> Direction {
         self.direction()
             .unwrap_or_else(|| self.axis().direction_positive())
     }
 
-    /// Measures the axis that an arrow point from [`Self::start`] to [`Self::end`] lays along.
+    /// Measures the axis that an arrow point from `start` to `end` lays along.
     pub const fn axis(&self) -> Axis {
         self.exposed_edge.axis().rotate()
     }
@@ -191,7 +197,7 @@ impl Span {
         })
     }
 
-    /// If `location` is between, at, or immediately next to [`Self::start`] and [`Self::end`],
+    /// If `location` is between, at, or immediately next to `start` and `end`,
     /// return [`Some`]. If location is next to either end, but not between, then the closest edge
     /// will be moved to `location`, growing [`Self`].
     ///
@@ -229,10 +235,15 @@ impl Span {
     }
 
     /// If `location` same [`Axis`] as [`Self`], return [`Some`]. If `location` is not at or
-    /// between [`Self::start`] or [`Self::end`], the closest end will be moved to `location`,
+    /// between `start` or `end`, the closest end will be moved to `location`,
     /// growing [`Self`].
     ///
     /// Returns [`None`] if `location` is not along the same [`Axis`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `location` is along the same [`Axis`] but [`Self::direction`] finds no direction
+    /// to it, which [`Self::contains`] should rule out.
     #[must_use]
     pub fn extend_to(&mut self, location: Coordinates) -> Option<()> {
         if !self.along_axis(location) {
@@ -376,6 +387,11 @@ impl Span {
         Some(())
     }
 
+    #[allow(unused)]
+    #[expect(
+        clippy::len_without_is_empty,
+        reason = "a span always covers at least one coordinate"
+    )]
     pub fn len(&self) -> usize {
         let Some(direction) = self.direction() else {
             // Start and end at in the same location, i.e., it only spans one coordinate.
@@ -176,7 +176,7 @@ fn bulk_grid_regions() {
     eprintln!("    Actual:          Expected:");
     for i in 0..regions.len().max(expected.len()) {
         let fmt = |(area, sides, cost): (Integer, Integer, Integer)| {
-            format!("({area:2}, {sides:2}, {cost:3})",)
+            format!("({area:2}, {sides:2}, {cost:3})")
         };
 
         let actual = regions[i];
@@ -191,7 +191,7 @@ fn bulk_grid_regions() {
             std::cmp::Ordering::Equal => {
                 eprintln!("  {plant} {} == {}", fmt(actual), fmt(expected));
             }
-        };
+        }
     }
 
     assert_eq!(
@@ -202,37 +202,3 @@ fn bulk_grid_regions() {
             .collect::<Vec<_>>()
     );
 }
-
-#[test]
-fn part_one() {
-    use super::super::{EXAMPLE_INPUT, LARGE_EXAMPLE_INPUT, SIMPLE_EXAMPLE_INPUT};
-
-    fn part_one(input_expected: &[(&str, Integer)]) {
-        for (input, expected) in input_expected {
-            assert_eq!(Plot::parse(input).unwrap().fencing_quote(), *expected);
-        }
-    }
-
-    part_one(&[
-        (EXAMPLE_INPUT, 140),
-        (LARGE_EXAMPLE_INPUT, 1930),
-        (SIMPLE_EXAMPLE_INPUT, 772),
-    ]);
-}
-
-#[test]
-fn part_two() {
-    use super::super::{EXAMPLE_INPUT, LARGE_EXAMPLE_INPUT, SIMPLE_EXAMPLE_INPUT_PART_TWO};
-
-    fn part_two(input_expected: &[(&str, Integer)]) {
-        for (input, expected) in input_expected {
-            assert_eq!(Plot::parse(input).unwrap().fencing_quote_bulk(), *expected);
-        }
-    }
-
-    part_two(&[
-        (EXAMPLE_INPUT, 80),
-        (LARGE_EXAMPLE_INPUT, 1206),
-        (SIMPLE_EXAMPLE_INPUT_PART_TWO, 368),
-    ]);
-}
@@ -10,3 +10,59 @@ pub mod day_09;
 pub mod day_10;
 pub mod day_11;
 pub mod day_12;
+
+use crate::runner::{days, Entry};
+
+/// Every day of 2024 that can be run.
+pub const DAYS: &[Entry] = days![
+    year_2024;
+    day_01;
+    day_02;
+    day_03;
+    day_04;
+    day_05;
+    day_06;
+    day_07;
+    day_08;
+    day_09;
+    day_10;
+    day_11;
+    day_12;
+];
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::Result,
+        solution::{Answer, Part},
+    };
+
+    /// Parse and solve both parts of a day, returning the answer to part two.
+    fn solve(day: u8, input: &str) -> Result<Answer> {
+        let solver = crate::entry(2024, day).unwrap().solver;
+        let parsed = solver.parse(input)?;
+        solver.solve(Part::One, &parsed)?;
+
+        solver.solve(Part::Two, &parsed)
+    }
+
+    /// Inputs that once made days panic, which should instead be rejected with errors (or ignored,
+    /// where the puzzle expects malformed text). The targets in `fuzz/` search for more.
+    #[test]
+    fn malformed_inputs() {
+        assert!(solve(1, "3   4\n1   x").is_err());
+
+        for input in [
+            "mul(1,)",
+            "mul(,2)",
+            "mul(1234,5)",
+            "mul(1,2",
+            "don't()mul(1,2)do(",
+        ] {
+            assert_eq!(Ok(Answer::from(0)), solve(3, input), "{input}");
+        }
+
+        assert!(solve(8, "").is_err());
+        assert!(solve(8, "\n.a").is_err());
+    }
+}